@@ -1,32 +1,66 @@
+use crate::crud::{CrudOutcome, ExpectedRowCount};
+use crate::sql_lang::expression::TableName;
 use crate::value::IntoSqlValue;
 use crate::{sql_lang, Database, ExecuteError};
 
-pub fn delete_rows<DB: Database, N: Into<String>>(table_name: N) -> DeleteBuilder<DB> {
+pub fn delete_rows<DB: Database, N: Into<TableName>>(table_name: N) -> DeleteBuilder<DB, false> {
 	DeleteBuilder {
 		statement: sql_lang::statement::Delete::build(table_name),
+		expected_row_count: None,
 	}
 }
 
-pub struct DeleteBuilder<DB: Database> {
-	pub(crate) statement: sql_lang::statement::delete::DeleteBuilder<DB>,
+pub struct DeleteBuilder<DB: Database, const HAS_WHERE: bool> {
+	pub(crate) statement: sql_lang::statement::delete::DeleteBuilder<DB, HAS_WHERE>,
+	pub(crate) expected_row_count: Option<ExpectedRowCount>,
 }
 
-impl<DB: Database> DeleteBuilder<DB> {
+impl<DB: Database, const HAS_WHERE: bool> DeleteBuilder<DB, HAS_WHERE> {
 	pub fn where_column_equal_to<N: Into<String>, V: IntoSqlValue<DB>>(
-		mut self,
+		self,
 		name: N,
 		value: V,
-	) -> Self {
-		self.statement = self.statement.where_column_equal_to(name, value);
-		self
+	) -> DeleteBuilder<DB, true> {
+		DeleteBuilder {
+			statement: self.statement.where_column_equal_to(name, value),
+			expected_row_count: self.expected_row_count,
+		}
+	}
+
+	pub fn with_where_clause(
+		self,
+		where_clause: sql_lang::clause::Where<DB>,
+	) -> DeleteBuilder<DB, true> {
+		DeleteBuilder {
+			statement: self.statement.with_where_clause(where_clause),
+			expected_row_count: self.expected_row_count,
+		}
 	}
 
-	pub fn with_where_clause(mut self, where_clause: sql_lang::clause::Where<DB>) -> Self {
-		self.statement = self.statement.with_where_clause(where_clause);
+	/// Guards against a WHERE clause that matches more (or fewer) rows than intended.
+	///
+	/// The statement is executed within a transaction (or a savepoint, if already within a
+	/// transaction), which is rolled back if the number of affected rows is not as expected.
+	/// An [UnexpectedRowCount](crate::error::CrudErrorKind::UnexpectedRowCount) error is then
+	/// returned.
+	pub fn expect_row_count(mut self, expected_row_count: ExpectedRowCount) -> Self {
+		self.expected_row_count = Some(expected_row_count);
 		self
 	}
+}
+
+impl<DB: Database> DeleteBuilder<DB, false> {
+	/// Explicitly deletes every row in the table, in place of a WHERE clause.
+	pub fn all_rows(self) -> DeleteBuilder<DB, true> {
+		DeleteBuilder {
+			statement: self.statement.all_rows(),
+			expected_row_count: self.expected_row_count,
+		}
+	}
+}
 
-	pub async fn execute(self, database: &mut DB::Connection) -> Result<(), ExecuteError> {
+impl<DB: Database> DeleteBuilder<DB, true> {
+	pub async fn execute(self, database: &mut DB::Connection) -> Result<CrudOutcome, ExecuteError> {
 		DB::execute_crud_delete(self, database).await
 	}
 }
@@ -0,0 +1,81 @@
+use std::fmt::Formatter;
+
+use sqlx::Connection;
+
+use crate::crud::CrudOutcome;
+use crate::error::{CrudError, CrudErrorKind};
+use crate::{Database, ExecuteError, FrozenSql};
+
+/// The number of rows that a CRUD update/delete is expected to affect.
+///
+/// See [UpdateBuilder::expect_row_count()](crate::crud::update::UpdateBuilder::expect_row_count)
+/// and [DeleteBuilder::expect_row_count()](crate::crud::delete::DeleteBuilder::expect_row_count).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExpectedRowCount {
+	Exactly(u64),
+	AtMost(u64),
+	/// An inclusive range. An [InvalidExpectedRowCount](CrudErrorKind::InvalidExpectedRowCount)
+	/// error is returned if the minimum exceeds the maximum.
+	Between(u64, u64),
+}
+
+impl ExpectedRowCount {
+	pub(crate) fn validate(&self) -> Result<(), CrudError> {
+		match *self {
+			Self::Between(min, max) if min > max => Err(CrudError::new(
+				CrudErrorKind::InvalidExpectedRowCount(*self),
+			)),
+			_ => Ok(()),
+		}
+	}
+
+	pub(crate) fn contains(&self, row_count: u64) -> bool {
+		match *self {
+			Self::Exactly(n) => row_count == n,
+			Self::AtMost(n) => row_count <= n,
+			Self::Between(min, max) => (min..=max).contains(&row_count),
+		}
+	}
+}
+
+impl std::fmt::Display for ExpectedRowCount {
+	fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+		match self {
+			Self::Exactly(n) => write!(f, "exactly {}", n),
+			Self::AtMost(n) => write!(f, "at most {}", n),
+			Self::Between(min, max) => write!(f, "between {} and {}", min, max),
+		}
+	}
+}
+
+/// Executes an update/delete statement, checking the number of affected rows if required.
+pub(crate) async fn execute_with_expected_row_count<DB: Database>(
+	sql: FrozenSql<DB>,
+	expected_row_count: Option<ExpectedRowCount>,
+	connection: &mut DB::Connection,
+) -> Result<CrudOutcome, ExecuteError> {
+	let Some(expected_row_count) = expected_row_count else {
+		return Ok(CrudOutcome::new(DB::execute_sql(sql, connection).await?));
+	};
+
+	expected_row_count.validate()?;
+
+	// begin() creates a savepoint when already within a transaction
+	let mut transaction = connection.begin().await.map_err(ExecuteError::new)?;
+
+	let rows_affected = DB::execute_sql(sql, &mut *transaction).await?;
+
+	if !expected_row_count.contains(rows_affected) {
+		transaction.rollback().await.map_err(ExecuteError::new)?;
+
+		return Err(CrudError::new(CrudErrorKind::UnexpectedRowCount {
+			expected: expected_row_count,
+			actual: rows_affected,
+		})
+		.into());
+	}
+
+	transaction.commit().await.map_err(ExecuteError::new)?;
+
+	Ok(CrudOutcome::new(rows_affected))
+}
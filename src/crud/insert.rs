@@ -1,7 +1,9 @@
+use crate::crud::CrudOutcome;
+use crate::sql_lang::expression::TableName;
 use crate::value::IntoSqlValue;
 use crate::{sql_lang, Database, ExecuteError};
 
-pub fn insert_row<DB: Database, N: Into<String>>(table_name: N) -> InsertBuilder<DB> {
+pub fn insert_row<DB: Database, N: Into<TableName>>(table_name: N) -> InsertBuilder<DB> {
 	InsertBuilder {
 		statement: sql_lang::statement::Insert::build(table_name),
 	}
@@ -17,7 +19,21 @@ impl<DB: Database> InsertBuilder<DB> {
 		self
 	}
 
-	pub async fn execute(self, database: &mut DB::Connection) -> Result<(), ExecuteError> {
+	pub async fn execute(self, database: &mut DB::Connection) -> Result<CrudOutcome, ExecuteError> {
 		DB::execute_crud_insert(self, database).await
 	}
+
+	/// Executes the insert, and returns the key generated by the database for the new row.
+	///
+	/// This uses a `returning` clause on postgres & sqlite. On mysql the key is taken from
+	/// `last_insert_id()`, which requires `key_column` to be the table's `AUTO_INCREMENT` column.
+	/// A [MissingGeneratedKey](crate::error::CrudErrorKind::MissingGeneratedKey) error is
+	/// returned if the table has no such column.
+	pub async fn execute_returning_id<N: Into<String>>(
+		self,
+		key_column: N,
+		database: &mut DB::Connection,
+	) -> Result<i64, ExecuteError> {
+		DB::execute_crud_insert_returning_id(self, key_column.into(), database).await
+	}
 }
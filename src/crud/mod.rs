@@ -1,9 +1,13 @@
 pub mod delete;
+pub mod expected_row_count;
 pub mod insert;
+pub mod outcome;
 pub mod replace;
 pub mod update;
 
 pub use delete::delete_rows;
+pub use expected_row_count::ExpectedRowCount;
 pub use insert::insert_row;
+pub use outcome::{CrudOutcome, ReplaceAction};
 pub use replace::replace_row;
 pub use update::update_rows;
@@ -0,0 +1,44 @@
+/// The result of executing one of the CRUD builders.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CrudOutcome {
+	rows_affected: u64,
+	replace_action: Option<ReplaceAction>,
+}
+
+impl CrudOutcome {
+	/// The number of rows inserted, updated or deleted.
+	pub fn rows_affected(&self) -> u64 {
+		self.rows_affected
+	}
+
+	/// The action taken by a [ReplaceBuilder](crate::crud::replace::ReplaceBuilder).
+	///
+	/// Always `None` for the other CRUD builders. Also `None` for a mysql
+	/// [native_upsert()](crate::crud::replace::ReplaceBuilder::native_upsert) which inserted a
+	/// row, or left it unchanged, as mysql reports both the same way.
+	pub fn replace_action(&self) -> Option<ReplaceAction> {
+		self.replace_action
+	}
+
+	pub(crate) fn new(rows_affected: u64) -> Self {
+		Self {
+			rows_affected,
+			replace_action: None,
+		}
+	}
+
+	pub(crate) fn replaced(rows_affected: u64, replace_action: Option<ReplaceAction>) -> Self {
+		Self {
+			rows_affected,
+			replace_action,
+		}
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReplaceAction {
+	Inserted,
+	Updated,
+	/// The row already existed, and there was nothing to update.
+	Unchanged,
+}
@@ -1,21 +1,29 @@
+use crate::crud::CrudOutcome;
 use crate::error::CrudErrorKind;
+use crate::sql_lang::clause::sql_where::WhereBuilder;
+use crate::sql_lang::clause::Where;
+use crate::sql_lang::expression::TableName;
+use crate::sql_lang::statement::{Insert, Select, Update};
 use crate::value::{IntoSqlValue, Value};
-use crate::{CrudError, Database, ExecuteError};
+use crate::vendor::requirements::SqlFeature;
+use crate::{CrudError, Database, ExecuteError, IntoSql, Sql, SyntaxError};
 
-pub fn replace_row<DB: Database, N: Into<String>>(
+pub fn replace_row<DB: Database, N: Into<TableName>>(
 	table_name: N,
 ) -> ReplaceBuilder<DB, false, false> {
 	ReplaceBuilder {
 		table_name: table_name.into(),
 		modifications: Vec::new(),
 		keys: Vec::new(),
+		native_upsert: false,
 	}
 }
 
 pub struct ReplaceBuilder<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool> {
-	pub(crate) table_name: String,
+	pub(crate) table_name: TableName,
 	pub(crate) modifications: Vec<Modification<DB>>,
 	pub(crate) keys: Vec<String>,
+	pub(crate) native_upsert: bool,
 }
 
 impl<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool>
@@ -29,6 +37,7 @@ impl<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool>
 		self.modifications.push(Modification {
 			name: name.into(),
 			value: value.into_sql_value(),
+			update_expression: None,
 			update: true,
 			insert: true,
 		});
@@ -37,6 +46,7 @@ impl<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool>
 			table_name: self.table_name,
 			modifications: self.modifications,
 			keys: self.keys,
+			native_upsert: self.native_upsert,
 		}
 	}
 
@@ -48,6 +58,7 @@ impl<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool>
 		self.modifications.push(Modification {
 			name: name.into(),
 			value: value.into_sql_value(),
+			update_expression: None,
 			update: true,
 			insert: false,
 		});
@@ -56,6 +67,7 @@ impl<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool>
 			table_name: self.table_name,
 			modifications: self.modifications,
 			keys: self.keys,
+			native_upsert: self.native_upsert,
 		}
 	}
 
@@ -67,6 +79,7 @@ impl<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool>
 		self.modifications.push(Modification {
 			name: name.into(),
 			value: value.into_sql_value(),
+			update_expression: None,
 			update: false,
 			insert: true,
 		});
@@ -75,8 +88,59 @@ impl<DB: Database, const HAS_KEYS: bool, const HAS_UPDATES: bool>
 			table_name: self.table_name,
 			modifications: self.modifications,
 			keys: self.keys,
+			native_upsert: self.native_upsert,
 		}
 	}
+
+	/// Sets the column to an arbitrary [Sql] expression when the row is updated
+	/// (e.g. `"counter"=("counter" + 1)`). The column is not inserted.
+	///
+	/// With [native_upsert()](Self::native_upsert) on postgres, both the table and the row
+	/// proposed for insertion are in scope, so an unqualified column reference is ambiguous.
+	/// Qualify the columns with the table instead (e.g.
+	/// `TableAndColumnReference::new("some_table", "counter") + 1`).
+	pub fn update_column_expression<N: Into<String>, E: Into<Sql<DB>>>(
+		mut self,
+		name: N,
+		expr: E,
+	) -> ReplaceBuilder<DB, HAS_KEYS, true> {
+		self.modifications.push(Modification {
+			name: name.into(),
+			value: None,
+			update_expression: Some(expr.into()),
+			update: true,
+			insert: false,
+		});
+
+		ReplaceBuilder {
+			table_name: self.table_name,
+			modifications: self.modifications,
+			keys: self.keys,
+			native_upsert: self.native_upsert,
+		}
+	}
+
+	/// Executes the replace as an atomic upsert, instead of a SELECT followed by an INSERT or
+	/// UPDATE.
+	///
+	/// This renders as `insert ... on conflict (<key columns>) do update set ...` on postgres,
+	/// and `insert ... on duplicate key update ...` on mysql.
+	///
+	/// Sqlite cannot report whether an upsert inserted or updated the row, so it instead executes
+	/// `insert ... on conflict (<key columns>) do nothing`, followed by an UPDATE if nothing was
+	/// inserted, within a transaction (or a savepoint, if already within a transaction).
+	///
+	/// Mysql reports an inserted row, and an existing row which already holds the new values, the
+	/// same way. The [replace_action()](CrudOutcome::replace_action) is `None` in both cases.
+	///
+	/// The key columns must be covered by a primary key or unique index, which the database uses
+	/// to detect the conflicting row. Unlike the default mode, a
+	/// [MultipleRowsWouldBeUpdated](CrudErrorKind::MultipleRowsWouldBeUpdated) error is never
+	/// produced, as the unique index guarantees at most one conflicting row.
+	pub fn native_upsert(mut self) -> Self {
+		self.native_upsert = true;
+		self
+	}
 }
 
 impl<DB: Database, const HAS_UPDATES: bool> ReplaceBuilder<DB, false, HAS_UPDATES> {
@@ -92,6 +156,7 @@ impl<DB: Database, const HAS_UPDATES: bool> ReplaceBuilder<DB, false, HAS_UPDATE
 			table_name: self.table_name,
 			modifications: self.modifications,
 			keys: self.keys,
+			native_upsert: self.native_upsert,
 		}
 	}
 }
@@ -105,14 +170,213 @@ impl<DB: Database> ReplaceBuilder<DB, true, true> {
 		Ok(())
 	}
 
-	pub async fn execute(self, database: &mut DB::Connection) -> Result<(), ExecuteError> {
+	pub async fn execute(self, database: &mut DB::Connection) -> Result<CrudOutcome, ExecuteError> {
 		DB::execute_crud_replace(self, database).await
 	}
+
+	/// The WHERE clause matching the existing row, by the values of its key columns.
+	pub(crate) fn key_where_clause(&self) -> Result<Where<DB>, SyntaxError> {
+		let mut where_clause: WhereBuilder<_, true, false> = WhereBuilder {
+			predicates: Vec::new(),
+		};
+
+		for key_name in &self.keys {
+			let value = self
+				.modifications
+				.iter()
+				.find_map(|modification| {
+					if modification.name == *key_name {
+						return Some(modification.value.clone());
+					}
+					None
+				})
+				.unwrap_or_default();
+
+			where_clause = where_clause.and_column_equal_to(key_name, value);
+		}
+
+		where_clause.finalize()
+	}
+
+	/// Selects the key columns of the existing row(s).
+	///
+	/// Where supported, the rows are locked (`for update`), so that they cannot change before
+	/// they are updated. `execute()` runs the lookup & the write within a transaction, which holds
+	/// the lock. Locking rules out an aggregate, so up to two rows are fetched instead of counting
+	/// them.
+	pub(crate) fn existing_rows_select(
+		&self,
+		where_clause: Where<DB>,
+	) -> Result<Select<DB>, SyntaxError> {
+		let select = Select::build(self.table_name.clone())
+			.select_columns(self.keys.clone())
+			.with_where_clause(where_clause)
+			.limit(2);
+
+		if DB::sql_supports_feature(SqlFeature::RowLocking) {
+			select.for_update().finalize()
+		} else {
+			select.finalize()
+		}
+	}
+
+	/// The insert statement, used when there is no existing row.
+	pub(crate) fn into_insert(self) -> Result<Insert<DB>, SyntaxError> {
+		let mut insert_statement = Insert::build(self.table_name);
+
+		for modification in self.modifications {
+			if modification.insert {
+				insert_statement = insert_statement.column(modification.name, modification.value);
+			}
+		}
+
+		insert_statement.finalize()
+	}
+
+	/// The update statement, used when there is an existing row. `None` if there are no columns
+	/// to update.
+	pub(crate) fn into_update(
+		self,
+		where_clause: Where<DB>,
+	) -> Result<Option<Update<DB>>, SyntaxError> {
+		let updates = self
+			.modifications
+			.into_iter()
+			.filter(|m| m.update)
+			.map(Modification::into_update_pair);
+
+		update_statement(self.table_name, updates, where_clause)
+	}
+
+	/// Splits the modifications into the insert statement, and the assignments made to the
+	/// conflicting row, for a [native_upsert()](Self::native_upsert).
+	pub(crate) fn into_upsert(self) -> Result<Upsert<DB>, SyntaxError> {
+		let mut insert_statement = Insert::build(self.table_name);
+		let mut updates = Vec::new();
+
+		for modification in self.modifications {
+			if modification.insert {
+				insert_statement =
+					insert_statement.column(modification.name.clone(), modification.value.clone());
+			}
+
+			if modification.update {
+				updates.push(modification.into_update_pair());
+			}
+		}
+
+		Ok(Upsert {
+			insert: insert_statement.finalize()?,
+			keys: self.keys,
+			updates,
+		})
+	}
+}
+
+/// An insert, which updates the existing row instead when it conflicts on the key columns.
+pub struct Upsert<DB: Database> {
+	pub(crate) insert: Insert<DB>,
+	pub(crate) keys: Vec<String>,
+	/// The column names & SQL assigned to the conflicting row. If empty, the row is left as is.
+	pub(crate) updates: Vec<(String, Sql<DB>)>,
+}
+
+impl<DB: Database> Upsert<DB> {
+	/// Splits off the assignments to the conflicting row, as a separate update of the row matched
+	/// by `where_clause`. A conflicting row is then left as is by the upsert itself.
+	#[cfg(feature = "sqlite")]
+	pub(crate) fn split_update(
+		&mut self,
+		where_clause: Where<DB>,
+	) -> Result<Option<Update<DB>>, SyntaxError> {
+		update_statement(
+			self.insert.table_name.clone(),
+			std::mem::take(&mut self.updates),
+			where_clause,
+		)
+	}
+}
+
+impl<DB: Database> From<Upsert<DB>> for Sql<DB> {
+	fn from(upsert: Upsert<DB>) -> Self {
+		DB::sql_from_upsert(upsert)
+	}
+}
+
+fn update_statement<DB: Database, U: IntoIterator<Item = (String, Sql<DB>)>>(
+	table_name: TableName,
+	updates: U,
+	where_clause: Where<DB>,
+) -> Result<Option<Update<DB>>, SyntaxError> {
+	// NOTE: we init a builder with the first (next()) value, and then add the rest
+	// This is done so that update_builder can be of type UpdateBuilder<_, true, true> throughout.
+
+	let mut updates = updates.into_iter();
+
+	let Some((name, value)) = updates.next() else {
+		return Ok(None);
+	};
+
+	let update_builder = updates.fold(
+		Update::build(table_name)
+			.with_where_clause(where_clause)
+			.update_column_expression(name, value),
+		|update_builder, (name, value)| update_builder.update_column_expression(name, value),
+	);
+
+	Ok(Some(update_builder.finalize()?))
+}
+
+/// Renders the standard `insert ... on conflict (<keys>) do update set ...` form of an upsert.
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+pub(crate) fn render_on_conflict<DB: Database>(upsert: Upsert<DB>) -> Sql<DB> {
+	use crate::sql_lang::expression::ColumnReference;
+
+	let mut sql = upsert.insert.into_sql().raw_append(" on conflict (");
+
+	for (i, key_name) in upsert.keys.into_iter().enumerate() {
+		if i > 0 {
+			sql = sql.raw_append(',');
+		}
+
+		sql = sql.append(ColumnReference::new(key_name));
+	}
+
+	if upsert.updates.is_empty() {
+		return sql.raw_append(") do nothing");
+	}
+
+	sql = sql.raw_append(") do update set ");
+
+	for (i, (name, value)) in upsert.updates.into_iter().enumerate() {
+		if i > 0 {
+			sql = sql.raw_append(',');
+		}
+
+		sql = sql
+			.append(ColumnReference::new(name))
+			.raw_append('=')
+			.append(value);
+	}
+
+	sql
 }
 
 pub(crate) struct Modification<DB: Database> {
 	pub name: String,
 	pub value: Option<Value<DB>>,
+	pub update_expression: Option<Sql<DB>>,
 	pub update: bool,
 	pub insert: bool,
 }
+
+impl<DB: Database> Modification<DB> {
+	/// The column name, and the SQL to assign to it when updating (either the expression or the
+	/// bound value).
+	pub fn into_update_pair(self) -> (String, Sql<DB>) {
+		match self.update_expression {
+			Some(expr) => (self.name, expr),
+			None => (self.name, self.value.into_sql()),
+		}
+	}
+}
@@ -1,44 +1,94 @@
+use crate::crud::{CrudOutcome, ExpectedRowCount};
+use crate::sql_lang::expression::TableName;
 use crate::value::IntoSqlValue;
-use crate::{sql_lang, Database, ExecuteError};
+use crate::{sql_lang, Database, ExecuteError, Sql};
 
-pub fn update_rows<DB: Database, N: Into<String>>(table_name: N) -> UpdateBuilder<DB, false> {
+pub fn update_rows<DB: Database, N: Into<TableName>>(
+	table_name: N,
+) -> UpdateBuilder<DB, false, false> {
 	UpdateBuilder {
 		statement: sql_lang::statement::Update::build(table_name),
+		expected_row_count: None,
 	}
 }
 
-pub struct UpdateBuilder<DB: Database, const HAS_UPDATES: bool> {
-	pub(crate) statement: sql_lang::statement::update::UpdateBuilder<DB, HAS_UPDATES>,
+pub struct UpdateBuilder<DB: Database, const HAS_UPDATES: bool, const HAS_WHERE: bool> {
+	pub(crate) statement: sql_lang::statement::update::UpdateBuilder<DB, HAS_UPDATES, HAS_WHERE>,
+	pub(crate) expected_row_count: Option<ExpectedRowCount>,
 }
 
-impl<DB: Database, const HAS_UPDATES: bool> UpdateBuilder<DB, HAS_UPDATES> {
+impl<DB: Database, const HAS_UPDATES: bool, const HAS_WHERE: bool>
+	UpdateBuilder<DB, HAS_UPDATES, HAS_WHERE>
+{
 	pub fn update_column<N: Into<String>, V: IntoSqlValue<DB>>(
 		self,
 		name: N,
 		value: V,
-	) -> UpdateBuilder<DB, true> {
+	) -> UpdateBuilder<DB, true, HAS_WHERE> {
 		UpdateBuilder {
 			statement: self.statement.update_column(name, value),
+			expected_row_count: self.expected_row_count,
+		}
+	}
+
+	/// Sets the column to an arbitrary [Sql] expression, instead of a bound value
+	/// (e.g. `set "counter"=("counter" + 1)`).
+	pub fn update_column_expression<N: Into<String>, E: Into<Sql<DB>>>(
+		self,
+		name: N,
+		expr: E,
+	) -> UpdateBuilder<DB, true, HAS_WHERE> {
+		UpdateBuilder {
+			statement: self.statement.update_column_expression(name, expr),
+			expected_row_count: self.expected_row_count,
 		}
 	}
 
 	pub fn where_column_equal_to<N: Into<String>, V: IntoSqlValue<DB>>(
-		mut self,
+		self,
 		name: N,
 		value: V,
-	) -> Self {
-		self.statement = self.statement.where_column_equal_to(name, value);
-		self
+	) -> UpdateBuilder<DB, HAS_UPDATES, true> {
+		UpdateBuilder {
+			statement: self.statement.where_column_equal_to(name, value),
+			expected_row_count: self.expected_row_count,
+		}
 	}
 
-	pub fn with_where_clause(mut self, where_clause: sql_lang::clause::Where<DB>) -> Self {
-		self.statement = self.statement.with_where_clause(where_clause);
+	pub fn with_where_clause(
+		self,
+		where_clause: sql_lang::clause::Where<DB>,
+	) -> UpdateBuilder<DB, HAS_UPDATES, true> {
+		UpdateBuilder {
+			statement: self.statement.with_where_clause(where_clause),
+			expected_row_count: self.expected_row_count,
+		}
+	}
+
+	/// Guards against a WHERE clause that matches more (or fewer) rows than intended.
+	///
+	/// The statement is executed within a transaction (or a savepoint, if already within a
+	/// transaction), which is rolled back if the number of affected rows is not as expected.
+	/// An [UnexpectedRowCount](crate::error::CrudErrorKind::UnexpectedRowCount) error is then
+	/// returned.
+	pub fn expect_row_count(mut self, expected_row_count: ExpectedRowCount) -> Self {
+		self.expected_row_count = Some(expected_row_count);
 		self
 	}
 }
 
-impl<DB: Database> UpdateBuilder<DB, true> {
-	pub async fn execute(self, database: &mut DB::Connection) -> Result<(), ExecuteError> {
+impl<DB: Database, const HAS_UPDATES: bool> UpdateBuilder<DB, HAS_UPDATES, false> {
+	/// Explicitly updates every row in the table, in place of a WHERE clause.
+	pub fn all_rows(self) -> UpdateBuilder<DB, HAS_UPDATES, true> {
+		UpdateBuilder {
+			statement: self.statement.all_rows(),
+			expected_row_count: self.expected_row_count,
+		}
+	}
+}
+
+impl<DB: Database> UpdateBuilder<DB, true, true> {
+	pub async fn execute(self, database: &mut DB::Connection) -> Result<CrudOutcome, ExecuteError> {
 		DB::execute_crud_update(self, database).await
 	}
 }
@@ -1,5 +1,6 @@
 use std::fmt::Formatter;
 
+use crate::crud::ExpectedRowCount;
 use crate::error::ExecuteError;
 
 #[derive(Debug)]
@@ -30,6 +31,15 @@ impl std::fmt::Display for CrudError {
 pub enum CrudErrorKind {
 	MultipleRowsWouldBeUpdated,
 	MissingKeyColumns,
+	UnexpectedRowCount {
+		expected: ExpectedRowCount,
+		actual: u64,
+	},
+	/// An [ExpectedRowCount::Between] whose minimum exceeds its maximum, which no row count
+	/// could satisfy.
+	InvalidExpectedRowCount(ExpectedRowCount),
+	/// The database did not generate a key for the inserted row.
+	MissingGeneratedKey,
 }
 
 impl std::fmt::Display for CrudErrorKind {
@@ -39,6 +49,17 @@ impl std::fmt::Display for CrudErrorKind {
 				write!(f, "Multiple rows would be updated")
 			}
 			CrudErrorKind::MissingKeyColumns => write!(f, "At least one key column is required"),
+			CrudErrorKind::UnexpectedRowCount { expected, actual } => {
+				write!(
+					f,
+					"Expected {} rows to be affected, but {} would be",
+					expected, actual
+				)
+			}
+			CrudErrorKind::InvalidExpectedRowCount(expected) => {
+				write!(f, "Invalid expected row count: {}", expected)
+			}
+			CrudErrorKind::MissingGeneratedKey => write!(f, "No key was generated"),
 		}
 	}
 }
@@ -42,8 +42,19 @@ impl std::fmt::Display for SyntaxError {
 #[derive(Debug)]
 pub enum SyntaxErrorKind {
 	MissingSelectPredicates,
+	MisplacedSelectAll,
 	MissingOrderByPredicates,
 	MissingGroupByPredicates,
+	MissingInsertColumns,
+	MissingInsertRows,
+	MismatchedInsertRowLength,
+	ConflictingInsertSources,
+	UnsupportedFeature,
+	InvalidCompoundMember,
+	MixedCompoundOperators,
+	MissingLockMode,
+	MissingJoinUsingColumns,
+	MissingDistinctOnColumns,
 	Other,
 }
 
@@ -53,6 +64,12 @@ impl std::fmt::Display for SyntaxErrorKind {
 			SyntaxErrorKind::MissingSelectPredicates => {
 				write!(f, "SQL SELECT statement must specify at least one column/expression for retrieval")
 			}
+			SyntaxErrorKind::MisplacedSelectAll => {
+				write!(
+					f,
+					"SQL SELECT * must be the first column/expression retrieved"
+				)
+			}
 			SyntaxErrorKind::MissingOrderByPredicates => {
 				write!(
 					f,
@@ -65,6 +82,51 @@ impl std::fmt::Display for SyntaxErrorKind {
 					"SQL GROUP BY statement must specify at least one column/expression"
 				)
 			}
+			SyntaxErrorKind::MissingInsertColumns => {
+				write!(f, "SQL INSERT statement must specify at least one column")
+			}
+			SyntaxErrorKind::MissingInsertRows => {
+				write!(f, "SQL INSERT statement must specify at least one row")
+			}
+			SyntaxErrorKind::MismatchedInsertRowLength => {
+				write!(
+					f,
+					"SQL INSERT statement rows must have one value for each column"
+				)
+			}
+			SyntaxErrorKind::ConflictingInsertSources => {
+				write!(
+					f,
+					"SQL INSERT statement cannot specify both column values and a SELECT statement"
+				)
+			}
+			SyntaxErrorKind::UnsupportedFeature => {
+				write!(f, "SQL feature is not supported by this database")
+			}
+			SyntaxErrorKind::InvalidCompoundMember => {
+				write!(
+					f,
+					"SQL compound query members cannot specify WITH, ORDER BY, LIMIT/OFFSET or locking clauses"
+				)
+			}
+			SyntaxErrorKind::MixedCompoundOperators => {
+				write!(
+					f,
+					"SQL compound query cannot combine INTERSECT with UNION or EXCEPT"
+				)
+			}
+			SyntaxErrorKind::MissingLockMode => {
+				write!(
+					f,
+					"SQL SELECT statement must specify FOR UPDATE or FOR SHARE to use NOWAIT or SKIP LOCKED"
+				)
+			}
+			SyntaxErrorKind::MissingJoinUsingColumns => {
+				write!(f, "SQL JOIN ... USING must specify at least one column")
+			}
+			SyntaxErrorKind::MissingDistinctOnColumns => {
+				write!(f, "SQL SELECT DISTINCT ON must specify at least one column")
+			}
 			SyntaxErrorKind::Other => write!(f, "Other"),
 		}
 	}
@@ -1,5 +1,6 @@
 use std::marker::PhantomData;
 
+use crate::sql_lang::expression::TableName;
 use crate::{crud, sql_lang, Database};
 
 /// Provides convenience methods on [Pool](sqlx::Pool) and [Transaction](sqlx::Transaction).
@@ -48,7 +49,7 @@ pub struct SqlStatementHelper<DB: Database> {
 }
 
 impl<DB: Database> SqlStatementHelper<DB> {
-	pub fn select<N: Into<String>>(
+	pub fn select<N: Into<TableName>>(
 		&self,
 		table_name: N,
 	) -> sql_lang::statement::select::SelectBuilder<DB, false, false, false, false, false> {
@@ -62,24 +63,39 @@ impl<DB: Database> SqlStatementHelper<DB> {
 		sql_lang::statement::Select::build_with_join(from_clause)
 	}
 
-	pub fn insert<N: Into<String>>(
+	pub fn compound(
+		&self,
+		select_statement: sql_lang::statement::Select<DB>,
+	) -> sql_lang::statement::compound::CompoundBuilder<DB, false, false> {
+		sql_lang::statement::Compound::build(select_statement)
+	}
+
+	pub fn insert<N: Into<TableName>>(
 		&self,
 		table_name: N,
 	) -> sql_lang::statement::insert::InsertBuilder<DB> {
 		sql_lang::statement::Insert::build(table_name)
 	}
 
-	pub fn update<N: Into<String>>(
+	pub fn insert_rows<N: Into<TableName>, C: Into<String>, I: IntoIterator<Item = C>>(
 		&self,
 		table_name: N,
-	) -> sql_lang::statement::update::UpdateBuilder<DB, false> {
+		column_names: I,
+	) -> sql_lang::statement::insert_rows::InsertRowsBuilder<DB, false> {
+		sql_lang::statement::InsertRows::build(table_name, column_names)
+	}
+
+	pub fn update<N: Into<TableName>>(
+		&self,
+		table_name: N,
+	) -> sql_lang::statement::update::UpdateBuilder<DB, false, false> {
 		sql_lang::statement::Update::build(table_name)
 	}
 
-	pub fn delete<N: Into<String>>(
+	pub fn delete<N: Into<TableName>>(
 		&self,
 		table_name: N,
-	) -> sql_lang::statement::delete::DeleteBuilder<DB> {
+	) -> sql_lang::statement::delete::DeleteBuilder<DB, false> {
 		sql_lang::statement::Delete::build(table_name)
 	}
 }
@@ -105,25 +121,28 @@ pub struct CrudHelper<DB: Database> {
 }
 
 impl<DB: Database> CrudHelper<DB> {
-	pub fn insert_row<N: Into<String>>(&self, table_name: N) -> crud::insert::InsertBuilder<DB> {
+	pub fn insert_row<N: Into<TableName>>(&self, table_name: N) -> crud::insert::InsertBuilder<DB> {
 		crud::insert_row(table_name)
 	}
 
-	pub fn update_rows<N: Into<String>>(
+	pub fn update_rows<N: Into<TableName>>(
 		&self,
 		table_name: N,
-	) -> crud::update::UpdateBuilder<DB, false> {
+	) -> crud::update::UpdateBuilder<DB, false, false> {
 		crud::update_rows(table_name)
 	}
 
-	pub fn replace_row<N: Into<String>>(
+	pub fn replace_row<N: Into<TableName>>(
 		&self,
 		table_name: N,
 	) -> crud::replace::ReplaceBuilder<DB, false, false> {
 		crud::replace_row(table_name)
 	}
 
-	pub fn delete_rows<N: Into<String>>(&self, table_name: N) -> crud::delete::DeleteBuilder<DB> {
+	pub fn delete_rows<N: Into<TableName>>(
+		&self,
+		table_name: N,
+	) -> crud::delete::DeleteBuilder<DB, false> {
 		crud::delete_rows(table_name)
 	}
 }
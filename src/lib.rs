@@ -78,6 +78,13 @@
 //! - The method
 //! 	[with_where_clause()](crate::crud::update::UpdateBuilder::with_where_clause)
 //! 	exists to specify more complex WHERE conditions.
+//! - The method [expect_row_count()](crate::crud::update::UpdateBuilder::expect_row_count)
+//!   rolls back the update/delete, and returns an error, if an unexpected number of rows would be
+//!   affected.
+//! - An update/delete without a WHERE clause won't compile, unless `all_rows()` is called to
+//!   explicitly affect every row in the table.
+//! - The method `update_column_expression()` sets a column to an SQL expression instead of a
+//!   value (e.g. `set counter = counter + 1`). It is also available when replacing a row.
 //!
 //! ### Replacing a single, uniquely identifiable, row (insert or update automatically)
 //! ```rust
@@ -105,6 +112,14 @@
 //! # }
 //! ```
 //! - The CRUD builder's `execute()` method's will accept an `sqlx` connection or transaction.
+//! - `execute()` returns a [CrudOutcome](crate::crud::CrudOutcome), reporting the number of rows
+//!   affected, and whether a replaced row was inserted or updated.
+//! - By default, replace emulation runs a SELECT followed by an INSERT or UPDATE, within a
+//!   transaction (or a savepoint, if already within one). On postgres & mysql, the SELECT locks an
+//!   existing row (`for update`) until that transaction ends. A missing row cannot be locked, so
+//!   concurrent inserts of the same key are only prevented by a unique index. Calling
+//!   `native_upsert()` instead executes an atomic upsert
+//!   (e.g. `insert ... on conflict ... do update`), which requires the key columns to be unique.
 //!
 //! ## Using the builders for SQL statements, clauses, etc.
 //!
@@ -258,7 +273,7 @@
 //! database-vendor coverage for *rendering* those builders to the vendors dialect.
 //!
 //! The former is easy to implement and is being done as needed. The latter is currently
-//! focused on `mysql` & `postgres` coverage. Contributions are welcome on either!
+//! focused on `mysql`, `postgres` & `sqlite` coverage. Contributions are welcome on either!
 //!
 //!
 //! # A note on the documentation for this crate
@@ -1,5 +1,5 @@
 use crate::error::SyntaxErrorKind;
-use crate::sql_lang::expression::{ColumnReference, TableAndColumnReference};
+use crate::sql_lang::expression::{ColumnReference, TableAndColumnReference, TableName};
 use crate::sql_lang::Sql;
 use crate::{Database, IntoRawSql, IntoSql, SyntaxError};
 
@@ -39,7 +39,7 @@ impl<DB: Database, Col: Into<String>> TryFrom<Vec<Col>> for GroupBy<DB> {
 
 // see: https://github.com/mikecaines/ursid-sqlx/issues/12
 /*
-impl<DB: Database, Tab: Into<String>, Col: Into<String>, const LEN: usize>
+impl<DB: Database, Tab: Into<TableName>, Col: Into<String>, const LEN: usize>
 	TryFrom<[(Tab, Col); LEN]> for GroupBy<DB>
 {
 	type Error = SyntaxError;
@@ -51,7 +51,7 @@ impl<DB: Database, Tab: Into<String>, Col: Into<String>, const LEN: usize>
 			.finalize()
 	}
 }*/
-/*impl<DB: Database, Tab: Into<String>, Col: Into<String>> TryFrom<Vec<(Tab, Col)>> for GroupBy<DB> {
+/*impl<DB: Database, Tab: Into<TableName>, Col: Into<String>> TryFrom<Vec<(Tab, Col)>> for GroupBy<DB> {
 	type Error = SyntaxError;
 
 	fn try_from(predicates: Vec<(Tab, Col)>) -> Result<Self, Self::Error> {
@@ -129,7 +129,7 @@ impl<DB: Database, const HAS_PREDICATES: bool> GroupByBuilder<DB, HAS_PREDICATES
 }
 
 impl<DB: Database, const HAS_PREDICATES: bool> GroupByBuilder<DB, HAS_PREDICATES, true> {
-	pub fn group_by_column<T: Into<String>, C: Into<String>>(
+	pub fn group_by_column<T: Into<TableName>, C: Into<String>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -171,7 +171,7 @@ impl<DB: Database, Col: Into<String>> FromIterator<Col> for GroupByBuilder<DB, t
 	}
 }
 
-impl<DB: Database, Tab: Into<String>, Col: Into<String>> FromIterator<(Tab, Col)>
+impl<DB: Database, Tab: Into<TableName>, Col: Into<String>> FromIterator<(Tab, Col)>
 	for GroupByBuilder<DB, true, true>
 {
 	fn from_iter<T: IntoIterator<Item = (Tab, Col)>>(iter: T) -> Self {
@@ -186,7 +186,7 @@ impl<DB: Database, Tab: Into<String>, Col: Into<String>> FromIterator<(Tab, Col)
 
 #[derive(Debug)]
 pub(crate) enum PredicateKind<DB: Database> {
-	TableAndColumn(String, String),
+	TableAndColumn(TableName, String),
 	Column(String),
 	Expression(Sql<DB>),
 }
@@ -7,6 +7,7 @@ pub mod sql_join;
 pub mod sql_on;
 pub mod sql_where;
 pub mod where_like;
+pub mod with;
 
 pub use group_by::GroupBy;
 pub use having::Having;
@@ -16,3 +17,4 @@ pub use sql_in::In;
 pub use sql_join::Join;
 pub use sql_on::SqlOn;
 pub use sql_where::Where;
+pub use with::With;
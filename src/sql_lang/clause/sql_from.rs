@@ -1,5 +1,7 @@
 use crate::sql_lang::clause::sql_join::{JoinBuilder, TableReferenceKind};
 use crate::sql_lang::clause::{Join, SqlOn};
+use crate::sql_lang::expression::TableName;
+use crate::sql_lang::statement::Select;
 use crate::{Database, IntoRawSql, Sql, SyntaxError};
 
 #[derive(Debug)]
@@ -18,7 +20,7 @@ impl<DB: Database> SqlFrom<DB> {
 		}
 	}
 
-	pub fn from_table_name<N: Into<String>>(table_name: N) -> Self {
+	pub fn from_table_name<N: Into<TableName>>(table_name: N) -> Self {
 		Self {
 			join: Join::from_table_name(table_name),
 		}
@@ -72,6 +74,71 @@ impl<DB: Database> SqlFromBuilder<DB> {
 		self
 	}
 
+	pub fn full_outer_join<T, A>(mut self, table_reference: T, alias: A, on: SqlOn<DB>) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+	{
+		self.join = self.join.full_outer_join(table_reference, alias, on);
+		self
+	}
+
+	pub fn cross_join<T, A>(mut self, table_reference: T, alias: A) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+	{
+		self.join = self.join.cross_join(table_reference, alias);
+		self
+	}
+
+	pub fn join_using<T, A, C, S>(mut self, table_reference: T, alias: A, column_names: S) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+		C: Into<String>,
+		S: IntoIterator<Item = C>,
+	{
+		self.join = self.join.join_using(table_reference, alias, column_names);
+		self
+	}
+
+	pub fn left_join_using<T, A, C, S>(
+		mut self,
+		table_reference: T,
+		alias: A,
+		column_names: S,
+	) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+		C: Into<String>,
+		S: IntoIterator<Item = C>,
+	{
+		self.join = self
+			.join
+			.left_join_using(table_reference, alias, column_names);
+		self
+	}
+
+	pub fn left_join_lateral<A: Into<String>>(
+		mut self,
+		select_statement: Select<DB>,
+		alias: A,
+	) -> Self {
+		self.join = self.join.left_join_lateral(select_statement, alias);
+		self
+	}
+
+	pub fn cross_join_lateral<A: Into<String>>(
+		mut self,
+		select_statement: Select<DB>,
+		alias: A,
+	) -> Self {
+		self.join = self.join.cross_join_lateral(select_statement, alias);
+		self
+	}
+
 	pub fn finalize(self) -> Result<SqlFrom<DB>, SyntaxError> {
 		Ok(SqlFrom {
 			join: self.join.finalize()?,
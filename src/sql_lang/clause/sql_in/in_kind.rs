@@ -1,11 +1,12 @@
-use crate::sql_lang::statement::Select;
+use crate::sql_lang::statement::{Compound, Select};
 use crate::value::Value;
 use crate::Database;
 
 #[derive(Debug)]
 pub(crate) enum InKind<DB: Database> {
 	Values(Vec<Option<Value<DB>>>),
-	Subquery(Select<DB>),
+	Subquery(Box<Select<DB>>),
+	CompoundSubquery(Box<Compound<DB>>),
 }
 
 impl<DB: Database> Clone for InKind<DB> {
@@ -13,6 +14,9 @@ impl<DB: Database> Clone for InKind<DB> {
 		match self {
 			InKind::Values(values) => InKind::Values(values.clone()),
 			InKind::Subquery(select_statement) => InKind::Subquery(select_statement.clone()),
+			InKind::CompoundSubquery(compound_statement) => {
+				InKind::CompoundSubquery(compound_statement.clone())
+			}
 		}
 	}
 }
@@ -1,5 +1,5 @@
 pub(crate) use crate::sql_lang::clause::sql_in::in_kind::InKind;
-use crate::sql_lang::statement::Select;
+use crate::sql_lang::statement::{Compound, Select};
 use crate::value::IntoSqlValue;
 use crate::{Database, IntoRawSql, Sql};
 
@@ -19,7 +19,13 @@ impl<DB: Database> In<DB> {
 
 	pub fn from_select_statement(select_statement: Select<DB>) -> Self {
 		Self {
-			kind: InKind::Subquery(select_statement),
+			kind: InKind::Subquery(Box::new(select_statement)),
+		}
+	}
+
+	pub fn from_compound_statement(compound_statement: Compound<DB>) -> Self {
+		Self {
+			kind: InKind::CompoundSubquery(Box::new(compound_statement)),
 		}
 	}
 }
@@ -44,6 +50,12 @@ impl<DB: Database> From<Select<DB>> for In<DB> {
 	}
 }
 
+impl<DB: Database> From<Compound<DB>> for In<DB> {
+	fn from(compound_statement: Compound<DB>) -> Self {
+		In::from_compound_statement(compound_statement)
+	}
+}
+
 impl<DB: Database> From<In<DB>> for Sql<DB> {
 	fn from(in_clause: In<DB>) -> Self {
 		match in_clause.kind {
@@ -70,7 +82,11 @@ impl<DB: Database> From<In<DB>> for Sql<DB> {
 			}
 
 			InKind::Subquery(select_clause) => IntoRawSql::<DB>::into_raw_sql("in (")
-				.append(select_clause)
+				.append(*select_clause)
+				.raw_append(')'),
+
+			InKind::CompoundSubquery(compound_statement) => IntoRawSql::<DB>::into_raw_sql("in (")
+				.append(*compound_statement)
 				.raw_append(')'),
 		}
 	}
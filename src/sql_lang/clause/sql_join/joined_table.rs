@@ -1,12 +1,13 @@
-use crate::sql_lang::clause::sql_join::{JoinKind, TableReferenceKind};
-use crate::sql_lang::clause::SqlOn;
+use crate::sql_lang::clause::sql_join::{JoinConstraint, JoinKind, TableReferenceKind};
 use crate::Database;
 
 #[derive(Debug)]
 pub struct JoinedTable<DB: Database> {
 	pub(crate) table_reference_kind: TableReferenceKind<DB>,
 	pub(crate) alias: Option<String>,
-	pub(crate) join: Option<(JoinKind, Option<SqlOn<DB>>)>,
+	pub(crate) join: Option<(JoinKind, Option<JoinConstraint<DB>>)>,
+	/// Allows a subquery to refer to the preceding tables (i.e. `join lateral (select ...)`).
+	pub(crate) lateral: bool,
 }
 
 impl<DB: Database> Clone for JoinedTable<DB> {
@@ -15,6 +16,7 @@ impl<DB: Database> Clone for JoinedTable<DB> {
 			table_reference_kind: self.table_reference_kind.clone(),
 			alias: self.alias.clone(),
 			join: self.join.clone(),
+			lateral: self.lateral,
 		}
 	}
 }
@@ -1,7 +1,11 @@
 pub(crate) use self::joined_table::JoinedTable;
 pub use self::table_reference::TableReferenceKind;
+use crate::error::SyntaxErrorKind;
+use crate::sql_lang::clause::where_like::{LogicalOp, PredicateKind};
 use crate::sql_lang::clause::SqlOn;
-use crate::sql_lang::expression::{ColumnReference, TableReference};
+use crate::sql_lang::expression::{ColumnReference, TableName, TableReference};
+use crate::sql_lang::statement::Select;
+use crate::vendor::requirements::SqlFeature;
 use crate::{Database, IntoRawSql, IntoSql, Sql, SyntaxError};
 
 mod joined_table;
@@ -12,6 +16,26 @@ pub(crate) enum JoinKind {
 	Inner,
 	Left,
 	Right,
+	FullOuter,
+	Cross,
+}
+
+/// The condition joining a table to the preceding tables.
+#[derive(Debug)]
+pub(crate) enum JoinConstraint<DB: Database> {
+	On(SqlOn<DB>),
+	/// Joins on equality of the named columns, present in both tables
+	/// (i.e. `using ("col1", "col2")`).
+	Using(Vec<String>),
+}
+
+impl<DB: Database> Clone for JoinConstraint<DB> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::On(on) => Self::On(on.clone()),
+			Self::Using(column_names) => Self::Using(column_names.clone()),
+		}
+	}
 }
 
 #[derive(Debug)]
@@ -30,16 +54,18 @@ impl<DB: Database> Join<DB> {
 				table_reference_kind: table_reference_kind.into(),
 				alias: Some(alias.into()),
 				join: None,
+				lateral: false,
 			}],
 		}
 	}
 
-	pub fn from_table_name<N: Into<String>>(table_name: N) -> Self {
+	pub fn from_table_name<N: Into<TableName>>(table_name: N) -> Self {
 		Self {
 			joined_tables: vec![JoinedTable {
 				table_reference_kind: TableReferenceKind::TableName(table_name.into()),
 				alias: None,
 				join: None,
+				lateral: false,
 			}],
 		}
 	}
@@ -64,11 +90,12 @@ impl<DB: Database> From<Join<DB>> for Sql<DB> {
 				table_reference_kind,
 				alias,
 				join,
+				lateral,
 			} = joined_table;
 
-			let mut join_on = None;
+			let mut join_constraint = None;
 
-			if let Some((kind, on)) = join {
+			if let Some((kind, constraint)) = join {
 				match kind {
 					JoinKind::Inner => {
 						sql = sql.raw_append(" inner join ");
@@ -79,9 +106,19 @@ impl<DB: Database> From<Join<DB>> for Sql<DB> {
 					JoinKind::Right => {
 						sql = sql.raw_append(" right join ");
 					}
+					JoinKind::FullOuter => {
+						sql = sql.raw_append(" full outer join ");
+					}
+					JoinKind::Cross => {
+						sql = sql.raw_append(" cross join ");
+					}
 				}
 
-				join_on = on;
+				join_constraint = constraint;
+			}
+
+			if lateral {
+				sql = sql.raw_append("lateral ");
 			}
 
 			sql = sql.append(match table_reference_kind {
@@ -93,14 +130,37 @@ impl<DB: Database> From<Join<DB>> for Sql<DB> {
 						.append(select_statement)
 						.raw_append(")")
 				}
+				TableReferenceKind::CompoundSubquery(compound_statement) => {
+					IntoRawSql::<DB>::into_raw_sql("(")
+						.append(compound_statement)
+						.raw_append(")")
+				}
 			});
 
 			if let Some(alias) = alias {
 				sql = sql.raw_append(' ').append(ColumnReference::new(alias));
 			}
 
-			if let Some(on) = join_on {
-				sql = sql.raw_append(' ').append(on);
+			match join_constraint {
+				Some(JoinConstraint::On(on)) => {
+					sql = sql.raw_append(' ').append(on);
+				}
+
+				Some(JoinConstraint::Using(column_names)) => {
+					sql = sql.raw_append(" using (");
+
+					for (i, column_name) in column_names.into_iter().enumerate() {
+						if i > 0 {
+							sql = sql.raw_append(", ");
+						}
+
+						sql = sql.append(ColumnReference::new(column_name));
+					}
+
+					sql = sql.raw_append(')');
+				}
+
+				None => {}
 			}
 		}
 
@@ -121,7 +181,8 @@ impl<DB: Database> JoinBuilder<DB> {
 		self.joined_tables.push(JoinedTable {
 			table_reference_kind: table_reference.into(),
 			alias: Some(alias.into()),
-			join: Some((JoinKind::Inner, Some(on))),
+			join: Some((JoinKind::Inner, Some(JoinConstraint::On(on)))),
+			lateral: false,
 		});
 
 		self
@@ -135,7 +196,8 @@ impl<DB: Database> JoinBuilder<DB> {
 		self.joined_tables.push(JoinedTable {
 			table_reference_kind: table_reference.into(),
 			alias: Some(alias.into()),
-			join: Some((JoinKind::Left, Some(on))),
+			join: Some((JoinKind::Left, Some(JoinConstraint::On(on)))),
+			lateral: false,
 		});
 
 		self
@@ -149,13 +211,183 @@ impl<DB: Database> JoinBuilder<DB> {
 		self.joined_tables.push(JoinedTable {
 			table_reference_kind: table_reference.into(),
 			alias: Some(alias.into()),
-			join: Some((JoinKind::Right, Some(on))),
+			join: Some((JoinKind::Right, Some(JoinConstraint::On(on)))),
+			lateral: false,
+		});
+
+		self
+	}
+
+	/// Joins the rows of both tables, padding the missing side with nulls when either table has no
+	/// matching row (i.e. `full outer join ... on ...`).
+	///
+	/// Not supported on mysql, which produces an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized.
+	pub fn full_outer_join<T, A>(mut self, table_reference: T, alias: A, on: SqlOn<DB>) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+	{
+		self.joined_tables.push(JoinedTable {
+			table_reference_kind: table_reference.into(),
+			alias: Some(alias.into()),
+			join: Some((JoinKind::FullOuter, Some(JoinConstraint::On(on)))),
+			lateral: false,
+		});
+
+		self
+	}
+
+	/// Joins every row of the table to every row of the preceding tables
+	/// (i.e. `cross join ...`).
+	pub fn cross_join<T, A>(mut self, table_reference: T, alias: A) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+	{
+		self.joined_tables.push(JoinedTable {
+			table_reference_kind: table_reference.into(),
+			alias: Some(alias.into()),
+			join: Some((JoinKind::Cross, None)),
+			lateral: false,
+		});
+
+		self
+	}
+
+	/// Inner joins on equality of the named columns, which both tables must have
+	/// (i.e. `inner join ... using ("col1", "col2")`).
+	pub fn join_using<T, A, C, S>(mut self, table_reference: T, alias: A, column_names: S) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+		C: Into<String>,
+		S: IntoIterator<Item = C>,
+	{
+		self.joined_tables.push(JoinedTable {
+			table_reference_kind: table_reference.into(),
+			alias: Some(alias.into()),
+			join: Some((
+				JoinKind::Inner,
+				Some(JoinConstraint::Using(
+					column_names.into_iter().map(|c| c.into()).collect(),
+				)),
+			)),
+			lateral: false,
+		});
+
+		self
+	}
+
+	/// Left joins on equality of the named columns, which both tables must have
+	/// (i.e. `left join ... using ("col1", "col2")`).
+	pub fn left_join_using<T, A, C, S>(
+		mut self,
+		table_reference: T,
+		alias: A,
+		column_names: S,
+	) -> Self
+	where
+		T: Into<TableReferenceKind<DB>>,
+		A: Into<String>,
+		C: Into<String>,
+		S: IntoIterator<Item = C>,
+	{
+		self.joined_tables.push(JoinedTable {
+			table_reference_kind: table_reference.into(),
+			alias: Some(alias.into()),
+			join: Some((
+				JoinKind::Left,
+				Some(JoinConstraint::Using(
+					column_names.into_iter().map(|c| c.into()).collect(),
+				)),
+			)),
+			lateral: false,
+		});
+
+		self
+	}
+
+	/// Left joins a subquery which can refer to the preceding tables, evaluating it once for each
+	/// of their rows (i.e. `left join lateral (select ...) "alias" on true`).
+	///
+	/// Not supported on sqlite, which produces an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized.
+	pub fn left_join_lateral<A: Into<String>>(
+		mut self,
+		select_statement: Select<DB>,
+		alias: A,
+	) -> Self {
+		self.joined_tables.push(JoinedTable {
+			table_reference_kind: TableReferenceKind::Subquery(select_statement),
+			alias: Some(alias.into()),
+			join: Some((
+				JoinKind::Left,
+				Some(JoinConstraint::On(SqlOn {
+					predicates: vec![(
+						LogicalOp::And,
+						PredicateKind::Expression("true".into_raw_sql()),
+					)],
+				})),
+			)),
+			lateral: true,
+		});
+
+		self
+	}
+
+	/// Joins a subquery which can refer to the preceding tables, evaluating it once for each of
+	/// their rows (i.e. `cross join lateral (select ...) "alias"`).
+	///
+	/// Not supported on sqlite, which produces an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized.
+	pub fn cross_join_lateral<A: Into<String>>(
+		mut self,
+		select_statement: Select<DB>,
+		alias: A,
+	) -> Self {
+		self.joined_tables.push(JoinedTable {
+			table_reference_kind: TableReferenceKind::Subquery(select_statement),
+			alias: Some(alias.into()),
+			join: Some((JoinKind::Cross, None)),
+			lateral: true,
 		});
 
 		self
 	}
 
 	pub fn finalize(self) -> Result<Join<DB>, SyntaxError> {
+		if self.joined_tables.iter().any(|t| t.lateral)
+			&& !DB::sql_supports_feature(SqlFeature::LateralJoin)
+		{
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::UnsupportedFeature,
+				"lateral join".to_string(),
+			));
+		}
+
+		for joined_table in &self.joined_tables {
+			match &joined_table.join {
+				Some((JoinKind::FullOuter, _))
+					if !DB::sql_supports_feature(SqlFeature::FullOuterJoin) =>
+				{
+					return Err(SyntaxError::new(
+						SyntaxErrorKind::UnsupportedFeature,
+						"full outer join".to_string(),
+					));
+				}
+
+				Some((_, Some(JoinConstraint::Using(column_names)))) if column_names.is_empty() => {
+					return Err(SyntaxError::new(
+						SyntaxErrorKind::MissingJoinUsingColumns,
+						"".to_string(),
+					));
+				}
+
+				_ => {}
+			}
+		}
+
 		Ok(Join {
 			joined_tables: self.joined_tables,
 		})
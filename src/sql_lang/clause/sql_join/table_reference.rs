@@ -1,10 +1,12 @@
-use crate::sql_lang::statement::Select;
+use crate::sql_lang::expression::TableName;
+use crate::sql_lang::statement::{Compound, Select};
 use crate::Database;
 
 #[derive(Debug)]
 pub enum TableReferenceKind<DB: Database> {
-	TableName(String),
+	TableName(TableName),
 	Subquery(Select<DB>),
+	CompoundSubquery(Compound<DB>),
 }
 
 impl<DB: Database> Clone for TableReferenceKind<DB> {
@@ -12,6 +14,9 @@ impl<DB: Database> Clone for TableReferenceKind<DB> {
 		match self {
 			TableReferenceKind::TableName(name) => Self::TableName(name.clone()),
 			TableReferenceKind::Subquery(select) => Self::Subquery(select.clone()),
+			TableReferenceKind::CompoundSubquery(compound) => {
+				Self::CompoundSubquery(compound.clone())
+			}
 		}
 	}
 }
@@ -22,14 +27,32 @@ impl<DB: Database> From<Select<DB>> for TableReferenceKind<DB> {
 	}
 }
 
+impl<DB: Database> From<Compound<DB>> for TableReferenceKind<DB> {
+	fn from(compound_statement: Compound<DB>) -> Self {
+		Self::CompoundSubquery(compound_statement)
+	}
+}
+
 impl<DB: Database> From<&str> for TableReferenceKind<DB> {
 	fn from(table_name: &str) -> Self {
-		Self::TableName(table_name.to_string())
+		Self::TableName(table_name.into())
 	}
 }
 
 impl<DB: Database> From<String> for TableReferenceKind<DB> {
 	fn from(table_name: String) -> Self {
+		Self::TableName(table_name.into())
+	}
+}
+
+impl<DB: Database> From<TableName> for TableReferenceKind<DB> {
+	fn from(table_name: TableName) -> Self {
 		Self::TableName(table_name)
 	}
 }
+
+impl<DB: Database, S: Into<String>, N: Into<String>> From<(S, N)> for TableReferenceKind<DB> {
+	fn from(table_name: (S, N)) -> Self {
+		Self::TableName(table_name.into())
+	}
+}
@@ -1,6 +1,6 @@
 use crate::sql_lang::clause::where_like::{render, WhereLike, WhereLikeBuilder};
 use crate::sql_lang::expression::grammar::ComparisonCombo;
-use crate::sql_lang::expression::TableAndColumnReference;
+use crate::sql_lang::expression::{TableAndColumnReference, TableName};
 use crate::{Database, IntoSql, Sql, SyntaxError};
 
 pub type SqlOn<DB> = WhereLike<DB, 'o'>;
@@ -20,7 +20,12 @@ pub type SqlOnBuilder<DB, const HAS_PREDICATES: bool> =
 	WhereLikeBuilder<DB, 'o', HAS_PREDICATES, true>;
 
 impl<DB: Database> SqlOnBuilder<DB, false> {
-	pub fn fk<FkTab: Into<String>, FkCol: Into<String>, PkTab: Into<String>, PkCol: Into<String>>(
+	pub fn fk<
+		FkTab: Into<TableName>,
+		FkCol: Into<String>,
+		PkTab: Into<TableName>,
+		PkCol: Into<String>,
+	>(
 		self,
 		fk_table_name: FkTab,
 		fk_column_name: FkCol,
@@ -44,9 +49,9 @@ impl<DB: Database> SqlOnBuilder<DB, false> {
 
 impl<DB: Database> SqlOnBuilder<DB, true> {
 	pub fn and_fk<
-		FkTab: Into<String>,
+		FkTab: Into<TableName>,
 		FkCol: Into<String>,
-		PkTab: Into<String>,
+		PkTab: Into<TableName>,
 		PkCol: Into<String>,
 	>(
 		self,
@@ -72,9 +77,9 @@ impl<DB: Database> SqlOnBuilder<DB, true> {
 
 impl<
 		DB: Database,
-		FkTab: Into<String>,
+		FkTab: Into<TableName>,
 		FkCol: Into<String>,
-		PkTab: Into<String>,
+		PkTab: Into<TableName>,
 		PkCol: Into<String>,
 	> TryFrom<(FkTab, FkCol, PkTab, PkCol)> for SqlOn<DB>
 where
@@ -2,7 +2,8 @@ pub(crate) use self::predicate::PredicateKind;
 use crate::error::SyntaxError;
 pub(crate) use crate::sql_lang::expression::grammar::LogicalOp;
 use crate::sql_lang::expression::{
-	ColumnReference, LogicalNot, SqlExpression, TableAndColumnReference,
+	exists, not_exists, ColumnReference, IntoSqlRange, LogicalNot, SqlExpression, Subquery,
+	TableAndColumnReference, TableName,
 };
 use crate::sql_lang::{ColRef, Sql};
 use crate::value::IntoSqlValue;
@@ -162,6 +163,36 @@ impl<DB: Database, const MODE: char, const HAS_JOIN: bool>
 			predicates: self.predicates,
 		}
 	}
+
+	/// Adds a predicate requiring the subquery to produce rows (i.e. `exists (select ...)`).
+	pub fn exists<Q: Into<Subquery<DB>>>(
+		mut self,
+		query: Q,
+	) -> WhereLikeBuilder<DB, MODE, true, HAS_JOIN> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(exists(query).into_sql()),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// Adds a predicate requiring the subquery to produce no rows (i.e. `not exists (select ...)`).
+	pub fn not_exists<Q: Into<Subquery<DB>>>(
+		mut self,
+		query: Q,
+	) -> WhereLikeBuilder<DB, MODE, true, HAS_JOIN> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(not_exists(query).into_sql()),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
 }
 
 impl<DB: Database, const MODE: char, const HAS_JOIN: bool>
@@ -215,6 +246,66 @@ impl<DB: Database, const MODE: char, const HAS_JOIN: bool>
 		}
 	}
 
+	/// Adds a predicate requiring the subquery to produce rows (i.e. `exists (select ...)`).
+	pub fn and_exists<Q: Into<Subquery<DB>>>(
+		mut self,
+		query: Q,
+	) -> WhereLikeBuilder<DB, MODE, true, HAS_JOIN> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(exists(query).into_sql()),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// Adds a predicate requiring the subquery to produce no rows (i.e. `not exists (select ...)`).
+	pub fn and_not_exists<Q: Into<Subquery<DB>>>(
+		mut self,
+		query: Q,
+	) -> WhereLikeBuilder<DB, MODE, true, HAS_JOIN> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(not_exists(query).into_sql()),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// Adds a predicate requiring the subquery to produce rows (i.e. `exists (select ...)`).
+	pub fn or_exists<Q: Into<Subquery<DB>>>(
+		mut self,
+		query: Q,
+	) -> WhereLikeBuilder<DB, MODE, true, HAS_JOIN> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(exists(query).into_sql()),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// Adds a predicate requiring the subquery to produce no rows (i.e. `not exists (select ...)`).
+	pub fn or_not_exists<Q: Into<Subquery<DB>>>(
+		mut self,
+		query: Q,
+	) -> WhereLikeBuilder<DB, MODE, true, HAS_JOIN> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(not_exists(query).into_sql()),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
 	pub fn finalize(self) -> Result<WhereLike<DB, MODE>, SyntaxError> {
 		Ok(WhereLike {
 			predicates: self.predicates,
@@ -331,10 +422,67 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, false, false> {
 			predicates: self.predicates,
 		}
 	}
+
+	pub fn column_like<N: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		name: N,
+		pattern: V,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				ColumnReference::new(name.into())
+					.like(pattern.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn column_between<N: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		name: N,
+		low: V,
+		high: V,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				ColumnReference::new(name.into())
+					.between(low.into_sql_value(), high.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// See [IntoSqlRange] for how each kind of range is rendered.
+	pub fn column_in_range<N: Into<String>, R: IntoSqlRange<DB>>(
+		mut self,
+		name: N,
+		range: R,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				range.into_range_predicate(ColumnReference::new(name.into()).into_sql()),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
 }
 
 impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, false, true> {
-	pub fn column_equal_to<T: Into<String>, C: Into<String>, V: IntoSqlValue<DB>>(
+	pub fn column_equal_to<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -356,7 +504,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, false, true> {
 		}
 	}
 
-	pub fn column_not_equal_to<T: Into<String>, C: Into<String>, V: IntoSqlValue<DB>>(
+	pub fn column_not_equal_to<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -376,7 +524,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, false, true> {
 		}
 	}
 
-	pub fn column_is_null<T: Into<String>, C: Into<String>>(
+	pub fn column_is_null<T: Into<TableName>, C: Into<String>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -404,7 +552,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, false, true> {
 		}
 	}
 
-	pub fn column_in<T: Into<String>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
+	pub fn column_in<T: Into<TableName>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -426,7 +574,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, false, true> {
 		}
 	}
 
-	pub fn column_not_in<T: Into<String>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
+	pub fn column_not_in<T: Into<TableName>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -448,6 +596,66 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, false, true> {
 			predicates: self.predicates,
 		}
 	}
+
+	pub fn column_like<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		pattern: V,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				TableAndColumnReference::new(table_name, column_name)
+					.like(pattern.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn column_between<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		low: V,
+		high: V,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				TableAndColumnReference::new(table_name, column_name)
+					.between(low.into_sql_value(), high.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// See [IntoSqlRange] for how each kind of range is rendered.
+	pub fn column_in_range<T: Into<TableName>, C: Into<String>, R: IntoSqlRange<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		range: R,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(range.into_range_predicate(
+				TableAndColumnReference::new(table_name, column_name).into_sql(),
+			)),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
 }
 
 impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, false> {
@@ -668,10 +876,124 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, false> {
 			predicates: self.predicates,
 		}
 	}
+
+	pub fn and_column_like<N: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		name: N,
+		pattern: V,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				ColumnReference::new(name.into())
+					.like(pattern.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn or_column_like<N: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		name: N,
+		pattern: V,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(
+				ColumnReference::new(name.into())
+					.like(pattern.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn and_column_between<N: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		name: N,
+		low: V,
+		high: V,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				ColumnReference::new(name.into())
+					.between(low.into_sql_value(), high.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// See [IntoSqlRange] for how each kind of range is rendered.
+	pub fn and_column_in_range<N: Into<String>, R: IntoSqlRange<DB>>(
+		mut self,
+		name: N,
+		range: R,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				range.into_range_predicate(ColumnReference::new(name.into()).into_sql()),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn or_column_between<N: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		name: N,
+		low: V,
+		high: V,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(
+				ColumnReference::new(name.into())
+					.between(low.into_sql_value(), high.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// See [IntoSqlRange] for how each kind of range is rendered.
+	pub fn or_column_in_range<N: Into<String>, R: IntoSqlRange<DB>>(
+		mut self,
+		name: N,
+		range: R,
+	) -> WhereLikeBuilder<DB, MODE, true, false> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(
+				range.into_range_predicate(ColumnReference::new(name.into()).into_sql()),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
 }
 
 impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
-	pub fn and_column_equal_to<T: Into<String>, C: Into<String>, V: IntoSqlValue<DB>>(
+	pub fn and_column_equal_to<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -693,7 +1015,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn and_column_not_equal_to<T: Into<String>, C: Into<String>, V: IntoSqlValue<DB>>(
+	pub fn and_column_not_equal_to<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -713,7 +1035,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn or_column_equal_to<T: Into<String>, C: Into<String>, V: IntoSqlValue<DB>>(
+	pub fn or_column_equal_to<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -735,7 +1057,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn or_column_not_equal_to<T: Into<String>, C: Into<String>, V: IntoSqlValue<DB>>(
+	pub fn or_column_not_equal_to<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -755,7 +1077,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn and_column_is_null<T: Into<String>, C: Into<String>>(
+	pub fn and_column_is_null<T: Into<TableName>, C: Into<String>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -783,7 +1105,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn or_column_is_null<T: Into<String>, C: Into<String>>(
+	pub fn or_column_is_null<T: Into<TableName>, C: Into<String>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -811,7 +1133,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn and_column_in<T: Into<String>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
+	pub fn and_column_in<T: Into<TableName>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -834,7 +1156,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 	}
 
 	pub fn and_column_not_in<
-		T: Into<String>,
+		T: Into<TableName>,
 		C: Into<String>,
 		I: Into<sql_lang::clause::In<DB>>,
 	>(
@@ -860,7 +1182,7 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn or_column_in<T: Into<String>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
+	pub fn or_column_in<T: Into<TableName>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -882,7 +1204,11 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 		}
 	}
 
-	pub fn or_column_not_in<T: Into<String>, C: Into<String>, I: Into<sql_lang::clause::In<DB>>>(
+	pub fn or_column_not_in<
+		T: Into<TableName>,
+		C: Into<String>,
+		I: Into<sql_lang::clause::In<DB>>,
+	>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -904,6 +1230,126 @@ impl<DB: Database, const MODE: char> WhereLikeBuilder<DB, MODE, true, true> {
 			predicates: self.predicates,
 		}
 	}
+
+	pub fn and_column_like<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		pattern: V,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				TableAndColumnReference::new(table_name, column_name)
+					.like(pattern.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn or_column_like<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		pattern: V,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(
+				TableAndColumnReference::new(table_name, column_name)
+					.like(pattern.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn and_column_between<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		low: V,
+		high: V,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(
+				TableAndColumnReference::new(table_name, column_name)
+					.between(low.into_sql_value(), high.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// See [IntoSqlRange] for how each kind of range is rendered.
+	pub fn and_column_in_range<T: Into<TableName>, C: Into<String>, R: IntoSqlRange<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		range: R,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::And,
+			PredicateKind::Expression(range.into_range_predicate(
+				TableAndColumnReference::new(table_name, column_name).into_sql(),
+			)),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	pub fn or_column_between<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		low: V,
+		high: V,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(
+				TableAndColumnReference::new(table_name, column_name)
+					.between(low.into_sql_value(), high.into_sql_value())
+					.into_sql(),
+			),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
+
+	/// See [IntoSqlRange] for how each kind of range is rendered.
+	pub fn or_column_in_range<T: Into<TableName>, C: Into<String>, R: IntoSqlRange<DB>>(
+		mut self,
+		table_name: T,
+		column_name: C,
+		range: R,
+	) -> WhereLikeBuilder<DB, MODE, true, true> {
+		self.predicates.push((
+			LogicalOp::Or,
+			PredicateKind::Expression(range.into_range_predicate(
+				TableAndColumnReference::new(table_name, column_name).into_sql(),
+			)),
+		));
+
+		WhereLikeBuilder {
+			predicates: self.predicates,
+		}
+	}
 }
 
 impl<DB: Database, const MODE: char, const HAS_PREDICATES: bool, const HAS_JOIN: bool> Clone
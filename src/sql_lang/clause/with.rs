@@ -0,0 +1,189 @@
+use crate::sql_lang::expression::{ColumnReference, TableReference};
+use crate::sql_lang::statement::{Compound, Select};
+use crate::{Database, IntoRawSql, Sql, SyntaxError};
+
+/// A WITH clause, defining common table expressions which the rest of the statement can refer to
+/// by name (i.e. `with "name" as (select ...) ...`).
+#[derive(Debug)]
+pub struct With<DB: Database> {
+	pub(crate) recursive: bool,
+	pub(crate) common_tables: Vec<CommonTable<DB>>,
+}
+
+impl<DB: Database> With<DB> {
+	pub fn build() -> WithBuilder<DB, false> {
+		WithBuilder {
+			recursive: false,
+			common_tables: vec![],
+		}
+	}
+}
+
+impl<DB: Database> Clone for With<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			recursive: self.recursive,
+			common_tables: self.common_tables.clone(),
+		}
+	}
+}
+
+impl<DB: Database> From<With<DB>> for Sql<DB> {
+	fn from(with_clause: With<DB>) -> Self {
+		let With {
+			recursive,
+			common_tables,
+		} = with_clause;
+
+		assert!(!common_tables.is_empty());
+
+		let mut sql: Sql<DB> = if recursive {
+			"with recursive ".into_raw_sql()
+		} else {
+			"with ".into_raw_sql()
+		};
+
+		for (i, common_table) in common_tables.into_iter().enumerate() {
+			let CommonTable {
+				name,
+				column_names,
+				query,
+			} = common_table;
+
+			if i > 0 {
+				sql = sql.raw_append(", ");
+			}
+
+			sql = sql.append(TableReference::new(name));
+
+			if !column_names.is_empty() {
+				sql = sql.raw_append(" (");
+
+				for (j, column_name) in column_names.into_iter().enumerate() {
+					if j > 0 {
+						sql = sql.raw_append(", ");
+					}
+
+					sql = sql.append(ColumnReference::new(column_name));
+				}
+
+				sql = sql.raw_append(')');
+			}
+
+			sql = sql.raw_append(" as (");
+
+			sql = match query {
+				CommonTableQuery::Select(select_statement) => sql.append(select_statement),
+				CommonTableQuery::Compound(compound_statement) => sql.append(compound_statement),
+			};
+
+			sql = sql.raw_append(')');
+		}
+
+		sql
+	}
+}
+
+#[derive(Debug)]
+pub(crate) struct CommonTable<DB: Database> {
+	pub(crate) name: String,
+	pub(crate) column_names: Vec<String>,
+	pub(crate) query: CommonTableQuery<DB>,
+}
+
+impl<DB: Database> Clone for CommonTable<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			name: self.name.clone(),
+			column_names: self.column_names.clone(),
+			query: self.query.clone(),
+		}
+	}
+}
+
+/// The statement defining the rows of a common table expression.
+///
+/// Recursive common table expressions are defined by a [Compound] statement, whose later members
+/// refer to the common table by name.
+#[derive(Debug)]
+pub enum CommonTableQuery<DB: Database> {
+	Select(Select<DB>),
+	Compound(Compound<DB>),
+}
+
+impl<DB: Database> Clone for CommonTableQuery<DB> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Select(select_statement) => Self::Select(select_statement.clone()),
+			Self::Compound(compound_statement) => Self::Compound(compound_statement.clone()),
+		}
+	}
+}
+
+impl<DB: Database> From<Select<DB>> for CommonTableQuery<DB> {
+	fn from(select_statement: Select<DB>) -> Self {
+		Self::Select(select_statement)
+	}
+}
+
+impl<DB: Database> From<Compound<DB>> for CommonTableQuery<DB> {
+	fn from(compound_statement: Compound<DB>) -> Self {
+		Self::Compound(compound_statement)
+	}
+}
+
+pub struct WithBuilder<DB: Database, const HAS_TABLES: bool> {
+	recursive: bool,
+	common_tables: Vec<CommonTable<DB>>,
+}
+
+impl<DB: Database, const HAS_TABLES: bool> WithBuilder<DB, HAS_TABLES> {
+	/// Allows the common table expressions to refer to themselves (i.e. `with recursive ...`).
+	pub fn recursive(mut self) -> Self {
+		self.recursive = true;
+		self
+	}
+
+	/// Adds a common table expression, whose columns are named by the query.
+	pub fn table<N: Into<String>, Q: Into<CommonTableQuery<DB>>>(
+		self,
+		name: N,
+		query: Q,
+	) -> WithBuilder<DB, true> {
+		self.table_with_columns(name, Vec::<String>::new(), query)
+	}
+
+	/// Adds a common table expression, with an explicit list of column names
+	/// (i.e. `with "name" ("col1", "col2") as (select ...)`).
+	pub fn table_with_columns<
+		N: Into<String>,
+		C: Into<String>,
+		S: IntoIterator<Item = C>,
+		Q: Into<CommonTableQuery<DB>>,
+	>(
+		mut self,
+		name: N,
+		column_names: S,
+		query: Q,
+	) -> WithBuilder<DB, true> {
+		self.common_tables.push(CommonTable {
+			name: name.into(),
+			column_names: column_names.into_iter().map(|c| c.into()).collect(),
+			query: query.into(),
+		});
+
+		WithBuilder {
+			recursive: self.recursive,
+			common_tables: self.common_tables,
+		}
+	}
+}
+
+impl<DB: Database> WithBuilder<DB, true> {
+	pub fn finalize(self) -> Result<With<DB>, SyntaxError> {
+		Ok(With {
+			recursive: self.recursive,
+			common_tables: self.common_tables,
+		})
+	}
+}
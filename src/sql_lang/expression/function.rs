@@ -3,6 +3,21 @@ use std::marker::PhantomData;
 use crate::sql_lang::Sql;
 use crate::Database;
 
+/// A function which can be evaluated over a window of rows (i.e. `... over (...)`).
+///
+/// Implemented by the dedicated window functions, as well as the aggregate functions.
+pub trait WindowFunction<DB: Database>: Sized {
+	#[doc(hidden)]
+	fn into_function_sql(self) -> Sql<DB>;
+
+	fn over(self, window: ast::Window<DB>) -> ast::Over<DB> {
+		ast::Over {
+			function: self.into_function_sql(),
+			window,
+		}
+	}
+}
+
 pub fn current_datetime<DB: Database>() -> ast::CurrentDatetime<DB> {
 	ast::CurrentDatetime { db: PhantomData }
 }
@@ -93,11 +108,56 @@ pub fn abs<DB: Database, E: Into<Sql<DB>>>(expr: E) -> ast::Abs<DB> {
 	ast::Abs { expr: expr.into() }
 }
 
+/// Creates an empty window specification (i.e. `over ()`), to be refined with a partition,
+/// ordering and frame.
+pub fn window<DB: Database>() -> ast::Window<DB> {
+	ast::Window {
+		partition_by: vec![],
+		order_by: None,
+		frame: None,
+	}
+}
+
+pub fn row_number<DB: Database>() -> ast::RowNumber<DB> {
+	ast::RowNumber { db: PhantomData }
+}
+
+pub fn rank<DB: Database>() -> ast::Rank<DB> {
+	ast::Rank { db: PhantomData }
+}
+
+pub fn dense_rank<DB: Database>() -> ast::DenseRank<DB> {
+	ast::DenseRank { db: PhantomData }
+}
+
+/// Retrieves the value of the row `offset` rows before the current row, within the window.
+pub fn lag<DB: Database, E: Into<Sql<DB>>>(expr: E, offset: u32) -> ast::Lag<DB> {
+	ast::Lag {
+		expr: expr.into(),
+		offset,
+	}
+}
+
+/// Retrieves the value of the row `offset` rows after the current row, within the window.
+pub fn lead<DB: Database, E: Into<Sql<DB>>>(expr: E, offset: u32) -> ast::Lead<DB> {
+	ast::Lead {
+		expr: expr.into(),
+		offset,
+	}
+}
+
+pub fn first_value<DB: Database, E: Into<Sql<DB>>>(expr: E) -> ast::FirstValue<DB> {
+	ast::FirstValue { expr: expr.into() }
+}
+
 pub mod ast {
 	use std::marker::PhantomData;
 
+	use super::WindowFunction;
+	use crate::sql_lang::clause::OrderBy;
+	use crate::sql_lang::expression::{ColumnReference, TableAndColumnReference, TableName};
 	use crate::sql_lang::Sql;
-	use crate::{Database, IntoRawSql};
+	use crate::{Database, IntoRawSql, IntoSql};
 
 	pub struct CurrentDatetime<DB: Database> {
 		pub(crate) db: PhantomData<DB>,
@@ -250,4 +310,227 @@ pub mod ast {
 				.raw_append(")")
 		}
 	}
+
+	impl<DB: Database> WindowFunction<DB> for Count<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			self.into()
+		}
+	}
+
+	impl<DB: Database> WindowFunction<DB> for Min<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			self.into()
+		}
+	}
+
+	impl<DB: Database> WindowFunction<DB> for Max<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			self.into()
+		}
+	}
+
+	pub struct RowNumber<DB: Database> {
+		pub(crate) db: PhantomData<DB>,
+	}
+
+	impl<DB: Database> WindowFunction<DB> for RowNumber<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			"row_number()".into_raw_sql()
+		}
+	}
+
+	pub struct Rank<DB: Database> {
+		pub(crate) db: PhantomData<DB>,
+	}
+
+	impl<DB: Database> WindowFunction<DB> for Rank<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			"rank()".into_raw_sql()
+		}
+	}
+
+	pub struct DenseRank<DB: Database> {
+		pub(crate) db: PhantomData<DB>,
+	}
+
+	impl<DB: Database> WindowFunction<DB> for DenseRank<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			"dense_rank()".into_raw_sql()
+		}
+	}
+
+	pub struct Lag<DB: Database> {
+		pub(crate) expr: Sql<DB>,
+		pub(crate) offset: u32,
+	}
+
+	impl<DB: Database> WindowFunction<DB> for Lag<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			let Lag { expr, offset } = self;
+
+			// mysql only accepts a literal (or placeholder) offset
+			IntoRawSql::<DB>::into_raw_sql("lag(")
+				.append(expr)
+				.raw_append(format!(", {})", offset))
+		}
+	}
+
+	pub struct Lead<DB: Database> {
+		pub(crate) expr: Sql<DB>,
+		pub(crate) offset: u32,
+	}
+
+	impl<DB: Database> WindowFunction<DB> for Lead<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			let Lead { expr, offset } = self;
+
+			IntoRawSql::<DB>::into_raw_sql("lead(")
+				.append(expr)
+				.raw_append(format!(", {})", offset))
+		}
+	}
+
+	pub struct FirstValue<DB: Database> {
+		pub(crate) expr: Sql<DB>,
+	}
+
+	impl<DB: Database> WindowFunction<DB> for FirstValue<DB> {
+		fn into_function_sql(self) -> Sql<DB> {
+			let FirstValue { expr } = self;
+
+			IntoRawSql::<DB>::into_raw_sql("first_value(")
+				.append(expr)
+				.raw_append(")")
+		}
+	}
+
+	/// A window function, evaluated over a window of rows.
+	///
+	/// See [WindowFunction::over()].
+	pub struct Over<DB: Database> {
+		pub(crate) function: Sql<DB>,
+		pub(crate) window: Window<DB>,
+	}
+
+	impl<DB: Database> From<Over<DB>> for Sql<DB> {
+		fn from(ast: Over<DB>) -> Self {
+			let Over { function, window } = ast;
+
+			function
+				.raw_append(" over (")
+				.append(window)
+				.raw_append(')')
+		}
+	}
+
+	/// The window specification of a window function (i.e. the `...` in `over (...)`).
+	///
+	/// See [window()](super::window).
+	pub struct Window<DB: Database> {
+		pub(crate) partition_by: Vec<Sql<DB>>,
+		pub(crate) order_by: Option<OrderBy<DB>>,
+		pub(crate) frame: Option<(FrameBound, FrameBound)>,
+	}
+
+	impl<DB: Database> Window<DB> {
+		pub fn partition_by_column<C: Into<String>>(mut self, column_name: C) -> Self {
+			self.partition_by
+				.push(ColumnReference::new(column_name).into_sql());
+			self
+		}
+
+		pub fn partition_by_table_column<T: Into<TableName>, C: Into<String>>(
+			mut self,
+			table_name: T,
+			column_name: C,
+		) -> Self {
+			self.partition_by
+				.push(TableAndColumnReference::new(table_name, column_name).into_sql());
+			self
+		}
+
+		pub fn partition_by_expression<E: Into<Sql<DB>>>(mut self, expr: E) -> Self {
+			self.partition_by.push(expr.into());
+			self
+		}
+
+		pub fn order_by(mut self, order_by_clause: OrderBy<DB>) -> Self {
+			self.order_by = Some(order_by_clause);
+			self
+		}
+
+		/// Restricts the window to a frame of rows, relative to the current row
+		/// (i.e. `rows between ... and ...`).
+		pub fn rows_between(mut self, start: FrameBound, end: FrameBound) -> Self {
+			self.frame = Some((start, end));
+			self
+		}
+	}
+
+	impl<DB: Database> From<Window<DB>> for Sql<DB> {
+		fn from(ast: Window<DB>) -> Self {
+			let Window {
+				partition_by,
+				order_by,
+				frame,
+			} = ast;
+
+			let mut sql: Sql<DB> = "".into_raw_sql();
+
+			if !partition_by.is_empty() {
+				sql = sql.raw_append("partition by ");
+
+				for (i, expr) in partition_by.into_iter().enumerate() {
+					if i > 0 {
+						sql = sql.raw_append(", ");
+					}
+
+					sql = sql.append(expr);
+				}
+			}
+
+			if let Some(order_by) = order_by {
+				if !sql.text.is_empty() {
+					sql = sql.raw_append(' ');
+				}
+
+				sql = sql.append(order_by);
+			}
+
+			if let Some((start, end)) = frame {
+				if !sql.text.is_empty() {
+					sql = sql.raw_append(' ');
+				}
+
+				sql = sql
+					.raw_append("rows between ")
+					.append(start)
+					.raw_append(" and ")
+					.append(end);
+			}
+
+			sql
+		}
+	}
+
+	#[derive(Debug, Clone, Copy)]
+	pub enum FrameBound {
+		UnboundedPreceding,
+		Preceding(u32),
+		CurrentRow,
+		Following(u32),
+		UnboundedFollowing,
+	}
+
+	impl<DB: Database> From<FrameBound> for Sql<DB> {
+		fn from(bound: FrameBound) -> Self {
+			match bound {
+				FrameBound::UnboundedPreceding => "unbounded preceding".into_raw_sql(),
+				FrameBound::Preceding(n) => format!("{} preceding", n).into_raw_sql(),
+				FrameBound::CurrentRow => "current row".into_raw_sql(),
+				FrameBound::Following(n) => format!("{} following", n).into_raw_sql(),
+				FrameBound::UnboundedFollowing => "unbounded following".into_raw_sql(),
+			}
+		}
+	}
 }
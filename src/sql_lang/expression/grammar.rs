@@ -1,6 +1,11 @@
 use std::marker::PhantomData;
 
+use crate::error::{SyntaxError, SyntaxErrorKind};
+use crate::sql_lang::expression::SqlExpression;
+use crate::sql_lang::statement::{Compound, Select};
 use crate::sql_lang::Sql;
+use crate::value::{IntoSqlArray, IntoSqlValue, Value};
+use crate::vendor::requirements::SqlFeature;
 use crate::{Database, IntoRawSql, IntoSql};
 
 #[derive(Debug, Clone, Copy)]
@@ -114,6 +119,395 @@ where
 	}
 }
 
+#[derive(Debug, Clone, Copy)]
+pub enum ArithmeticOp {
+	Add,
+	Subtract,
+	Multiply,
+	Divide,
+	Modulo,
+}
+
+impl<DB: Database> From<ArithmeticOp> for Sql<DB> {
+	fn from(op: ArithmeticOp) -> Self {
+		match op {
+			ArithmeticOp::Add => '+'.into_raw_sql(),
+			ArithmeticOp::Subtract => '-'.into_raw_sql(),
+			ArithmeticOp::Multiply => '*'.into_raw_sql(),
+			ArithmeticOp::Divide => '/'.into_raw_sql(),
+			ArithmeticOp::Modulo => '%'.into_raw_sql(),
+		}
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct ArithmeticCombo<DB: Database, L: Into<Sql<DB>>, R: Into<Sql<DB>>> {
+	pub(crate) db: PhantomData<DB>,
+	pub(crate) lhs: L,
+	pub(crate) op: ArithmeticOp,
+	pub(crate) rhs: R,
+}
+
+impl<DB: Database, L, R> From<ArithmeticCombo<DB, L, R>> for Sql<DB>
+where
+	L: Into<Sql<DB>>,
+	R: Into<Sql<DB>>,
+{
+	fn from(expr: ArithmeticCombo<DB, L, R>) -> Self {
+		let ArithmeticCombo {
+			db: _,
+			lhs,
+			op,
+			rhs,
+		} = expr;
+
+		// always parenthesized, so that nested combos keep the order they were built in
+		IntoRawSql::<DB>::into_raw_sql('(')
+			.append(lhs)
+			.raw_append(' ')
+			.append(op)
+			.raw_append(' ')
+			.append(rhs)
+			.raw_append(')')
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Negation<DB: Database, T: Into<Sql<DB>>> {
+	pub(crate) db: PhantomData<DB>,
+	pub(crate) content: T,
+}
+
+impl<DB: Database, T> From<Negation<DB, T>> for Sql<DB>
+where
+	T: Into<Sql<DB>>,
+{
+	fn from(expr: Negation<DB, T>) -> Self {
+		let Negation { db: _, content } = expr;
+
+		// the parenthesis also prevents a nested negation from forming a "--" comment
+		IntoRawSql::<DB>::into_raw_sql("-(")
+			.append(content)
+			.raw_append(')')
+	}
+}
+
+/// A pattern match (i.e. `<lhs> like <pattern>`).
+///
+/// The pattern's `%` and `_` wildcards can be escaped with a backslash,
+/// see [pattern](super::pattern).
+#[derive(Debug)]
+pub struct Like<DB: Database> {
+	pub(crate) lhs: Sql<DB>,
+	pub(crate) pattern: Sql<DB>,
+	pub(crate) not: bool,
+	pub(crate) case_insensitive: bool,
+}
+
+impl<DB: Database> Clone for Like<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			lhs: self.lhs.clone(),
+			pattern: self.pattern.clone(),
+			not: self.not,
+			case_insensitive: self.case_insensitive,
+		}
+	}
+}
+
+impl<DB: Database> From<Like<DB>> for Sql<DB> {
+	fn from(expr: Like<DB>) -> Self {
+		DB::sql_from_expr_like(expr)
+	}
+}
+
+/// A range test (i.e. `<lhs> between <low> and <high>`), which includes both bounds.
+#[derive(Debug)]
+pub struct Between<DB: Database> {
+	pub(crate) lhs: Sql<DB>,
+	pub(crate) low: Sql<DB>,
+	pub(crate) high: Sql<DB>,
+	pub(crate) not: bool,
+}
+
+impl<DB: Database> Clone for Between<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			lhs: self.lhs.clone(),
+			low: self.low.clone(),
+			high: self.high.clone(),
+			not: self.not,
+		}
+	}
+}
+
+impl<DB: Database> From<Between<DB>> for Sql<DB> {
+	fn from(expr: Between<DB>) -> Self {
+		let Between {
+			lhs,
+			low,
+			high,
+			not,
+		} = expr;
+
+		lhs.raw_append(if not { " not between " } else { " between " })
+			.append(low)
+			.raw_append(" and ")
+			.append(high)
+	}
+}
+
+/// A Rust range of values, which an expression can be tested against.
+///
+/// A [Range](std::ops::Range) excludes its end (i.e. `(<lhs> >= <start> and <lhs> < <end>)`),
+/// while a [RangeInclusive](std::ops::RangeInclusive) renders as `<lhs> between <start> and <end>`.
+pub trait IntoSqlRange<DB: Database> {
+	fn into_range_predicate(self, lhs: Sql<DB>) -> Sql<DB>;
+}
+
+impl<DB: Database, V: IntoSqlValue<DB>> IntoSqlRange<DB> for std::ops::Range<V> {
+	fn into_range_predicate(self, lhs: Sql<DB>) -> Sql<DB> {
+		let std::ops::Range { start, end } = self;
+
+		Parenthesis::new(
+			lhs.clone()
+				.greater_than_equal_to(start.into_sql_value())
+				.and(lhs.less_than(end.into_sql_value())),
+		)
+		.into_sql()
+	}
+}
+
+impl<DB: Database, V: IntoSqlValue<DB>> IntoSqlRange<DB> for std::ops::RangeInclusive<V> {
+	fn into_range_predicate(self, lhs: Sql<DB>) -> Sql<DB> {
+		let (start, end) = self.into_inner();
+
+		lhs.between(start.into_sql_value(), end.into_sql_value())
+			.into_sql()
+	}
+}
+
+/// The statement of a subquery used within an expression.
+///
+/// See [exists()], [ScalarSubquery], [any()] and [all()].
+#[derive(Debug)]
+pub enum Subquery<DB: Database> {
+	Select(Select<DB>),
+	Compound(Compound<DB>),
+}
+
+impl<DB: Database> Clone for Subquery<DB> {
+	fn clone(&self) -> Self {
+		match self {
+			Self::Select(select_statement) => Self::Select(select_statement.clone()),
+			Self::Compound(compound_statement) => Self::Compound(compound_statement.clone()),
+		}
+	}
+}
+
+impl<DB: Database> From<Select<DB>> for Subquery<DB> {
+	fn from(select_statement: Select<DB>) -> Self {
+		Self::Select(select_statement)
+	}
+}
+
+impl<DB: Database> From<Compound<DB>> for Subquery<DB> {
+	fn from(compound_statement: Compound<DB>) -> Self {
+		Self::Compound(compound_statement)
+	}
+}
+
+impl<DB: Database> From<Subquery<DB>> for Sql<DB> {
+	fn from(query: Subquery<DB>) -> Self {
+		match query {
+			Subquery::Select(select_statement) => select_statement.into(),
+			Subquery::Compound(compound_statement) => compound_statement.into(),
+		}
+	}
+}
+
+/// Tests whether a subquery produces any rows (i.e. `exists (select ...)`).
+///
+/// The subquery can refer to the tables and aliases of the outer query.
+pub fn exists<DB: Database, Q: Into<Subquery<DB>>>(query: Q) -> Exists<DB> {
+	Exists {
+		query: query.into(),
+		not: false,
+	}
+}
+
+/// Tests whether a subquery produces no rows (i.e. `not exists (select ...)`).
+pub fn not_exists<DB: Database, Q: Into<Subquery<DB>>>(query: Q) -> Exists<DB> {
+	Exists {
+		query: query.into(),
+		not: true,
+	}
+}
+
+#[derive(Debug)]
+pub struct Exists<DB: Database> {
+	pub(crate) query: Subquery<DB>,
+	pub(crate) not: bool,
+}
+
+impl<DB: Database> Clone for Exists<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			query: self.query.clone(),
+			not: self.not,
+		}
+	}
+}
+
+impl<DB: Database> From<Exists<DB>> for Sql<DB> {
+	fn from(expr: Exists<DB>) -> Self {
+		let Exists { query, not } = expr;
+
+		IntoRawSql::<DB>::into_raw_sql(if not { "not exists (" } else { "exists (" })
+			.append(query)
+			.raw_append(')')
+	}
+}
+
+/// A subquery producing a single value, usable wherever an expression is (i.e. `(select ...)`).
+#[derive(Debug)]
+pub struct ScalarSubquery<DB: Database> {
+	pub(crate) query: Subquery<DB>,
+}
+
+impl<DB: Database> ScalarSubquery<DB> {
+	pub fn new<Q: Into<Subquery<DB>>>(query: Q) -> Self {
+		Self {
+			query: query.into(),
+		}
+	}
+}
+
+impl<DB: Database> Clone for ScalarSubquery<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			query: self.query.clone(),
+		}
+	}
+}
+
+impl<DB: Database> From<ScalarSubquery<DB>> for Sql<DB> {
+	fn from(expr: ScalarSubquery<DB>) -> Self {
+		IntoRawSql::<DB>::into_raw_sql('(')
+			.append(expr.query)
+			.raw_append(')')
+	}
+}
+
+/// Compares against each row of a subquery, succeeding if any comparison does
+/// (i.e. `<lhs> = any (select ...)`).
+///
+/// Sqlite does not support quantified comparisons, and produces an
+/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error.
+pub fn any<DB: Database, Q: Into<Subquery<DB>>>(query: Q) -> Result<Quantified<DB>, SyntaxError> {
+	quantified_subquery(Quantifier::Any, query.into())
+}
+
+/// Compares against each row of a subquery, succeeding if every comparison does
+/// (i.e. `<lhs> > all (select ...)`).
+///
+/// Sqlite does not support quantified comparisons, and produces an
+/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error.
+pub fn all<DB: Database, Q: Into<Subquery<DB>>>(query: Q) -> Result<Quantified<DB>, SyntaxError> {
+	quantified_subquery(Quantifier::All, query.into())
+}
+
+fn quantified_subquery<DB: Database>(
+	quantifier: Quantifier,
+	query: Subquery<DB>,
+) -> Result<Quantified<DB>, SyntaxError> {
+	if !DB::sql_supports_feature(SqlFeature::QuantifiedSubquery) {
+		return Err(SyntaxError::new(
+			SyntaxErrorKind::UnsupportedFeature,
+			"quantified subquery".to_string(),
+		));
+	}
+
+	Ok(Quantified {
+		quantifier,
+		operand: QuantifiedOperand::Subquery(Box::new(query)),
+	})
+}
+
+/// Compares against each element of an array, bound as a single value (i.e. `<lhs> = any($1)`).
+///
+/// Only available for vendors implementing [IntoSqlArray] (i.e. Postgres).
+pub fn any_array<DB: Database, A: IntoSqlArray<DB>>(values: A) -> Quantified<DB> {
+	Quantified {
+		quantifier: Quantifier::Any,
+		operand: QuantifiedOperand::Array(values.into_sql_array()),
+	}
+}
+
+/// Compares against each element of an array, bound as a single value (i.e. `<lhs> <> all($1)`).
+///
+/// Only available for vendors implementing [IntoSqlArray] (i.e. Postgres).
+pub fn all_array<DB: Database, A: IntoSqlArray<DB>>(values: A) -> Quantified<DB> {
+	Quantified {
+		quantifier: Quantifier::All,
+		operand: QuantifiedOperand::Array(values.into_sql_array()),
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Quantifier {
+	Any,
+	All,
+}
+
+/// The right-hand side of a quantified comparison.
+///
+/// See [any()], [all()], [any_array()] and [all_array()].
+#[derive(Debug)]
+pub struct Quantified<DB: Database> {
+	pub(crate) quantifier: Quantifier,
+	pub(crate) operand: QuantifiedOperand<DB>,
+}
+
+#[derive(Debug)]
+pub(crate) enum QuantifiedOperand<DB: Database> {
+	Subquery(Box<Subquery<DB>>),
+	Array(Option<Value<DB>>),
+}
+
+impl<DB: Database> Clone for Quantified<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			quantifier: self.quantifier,
+			operand: match &self.operand {
+				QuantifiedOperand::Subquery(query) => QuantifiedOperand::Subquery(query.clone()),
+				QuantifiedOperand::Array(values) => QuantifiedOperand::Array(values.clone()),
+			},
+		}
+	}
+}
+
+impl<DB: Database> From<Quantified<DB>> for Sql<DB> {
+	fn from(expr: Quantified<DB>) -> Self {
+		let Quantified {
+			quantifier,
+			operand,
+		} = expr;
+
+		let sql: Sql<DB> = match quantifier {
+			Quantifier::Any => "any".into_raw_sql(),
+			Quantifier::All => "all".into_raw_sql(),
+		};
+
+		match operand {
+			QuantifiedOperand::Subquery(query) => {
+				sql.raw_append(" (").append(*query).raw_append(')')
+			}
+			QuantifiedOperand::Array(values) => sql.raw_append('(').append(values).raw_append(')'),
+		}
+	}
+}
+
 #[derive(Debug, Clone)]
 pub struct LogicalNot<DB: Database, T: Into<Sql<DB>>> {
 	pub(crate) db: PhantomData<DB>,
@@ -189,3 +583,178 @@ where
 			.raw_append(')')
 	}
 }
+
+/// Starts a searched CASE expression, with its first branch
+/// (i.e. `case when <condition> then <result> ... end`).
+pub fn case_when<DB: Database, C: Into<Sql<DB>>, R: Into<Sql<DB>>>(
+	condition: C,
+	result: R,
+) -> Case<DB> {
+	Case {
+		operand: None,
+		branches: vec![(condition.into(), result.into())],
+		else_result: None,
+	}
+}
+
+/// Starts a simple CASE expression, which compares the operand against the value of each branch
+/// (i.e. `case <operand> when <value> then <result> ... end`).
+pub fn case<DB: Database, O: Into<Sql<DB>>>(operand: O) -> SimpleCase<DB> {
+	SimpleCase {
+		operand: operand.into(),
+	}
+}
+
+#[derive(Debug)]
+pub struct Case<DB: Database> {
+	pub(crate) operand: Option<Sql<DB>>,
+	pub(crate) branches: Vec<(Sql<DB>, Sql<DB>)>,
+	pub(crate) else_result: Option<Sql<DB>>,
+}
+
+impl<DB: Database> Case<DB> {
+	/// Adds a branch, which is either a condition (searched CASE), or a value to compare the
+	/// operand against (simple CASE).
+	pub fn when<W: Into<Sql<DB>>, R: Into<Sql<DB>>>(mut self, when: W, result: R) -> Self {
+		self.branches.push((when.into(), result.into()));
+		self
+	}
+
+	/// Sets the result when no branch matches. Without it, the result is `null`.
+	pub fn else_<R: Into<Sql<DB>>>(mut self, result: R) -> Self {
+		self.else_result = Some(result.into());
+		self
+	}
+}
+
+impl<DB: Database> Clone for Case<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			operand: self.operand.clone(),
+			branches: self.branches.clone(),
+			else_result: self.else_result.clone(),
+		}
+	}
+}
+
+impl<DB: Database> From<Case<DB>> for Sql<DB> {
+	fn from(expr: Case<DB>) -> Self {
+		let Case {
+			operand,
+			branches,
+			else_result,
+		} = expr;
+
+		let mut sql: Sql<DB> = "case".into_raw_sql();
+
+		if let Some(operand) = operand {
+			sql = sql.raw_append(' ').append(operand);
+		}
+
+		for (when, result) in branches {
+			sql = sql
+				.raw_append(" when ")
+				.append(when)
+				.raw_append(" then ")
+				.append(result);
+		}
+
+		if let Some(else_result) = else_result {
+			sql = sql.raw_append(" else ").append(else_result);
+		}
+
+		sql.raw_append(" end")
+	}
+}
+
+/// A simple CASE expression, awaiting its first branch.
+///
+/// See [case()].
+#[derive(Debug)]
+pub struct SimpleCase<DB: Database> {
+	pub(crate) operand: Sql<DB>,
+}
+
+impl<DB: Database> SimpleCase<DB> {
+	pub fn when<V: Into<Sql<DB>>, R: Into<Sql<DB>>>(self, value: V, result: R) -> Case<DB> {
+		Case {
+			operand: Some(self.operand),
+			branches: vec![(value.into(), result.into())],
+			else_result: None,
+		}
+	}
+}
+
+/// Implements the [std::ops] arithmetic operators for an expression type, producing the same
+/// ASTs as the [SqlExpression](super::SqlExpression) methods.
+macro_rules! impl_arithmetic_ops {
+	($([$($param:ident),*] $ty:ty),+ $(,)?) => {
+		$(
+			impl<DB: Database, $($param: Into<Sql<DB>>,)* Rhs: Into<Sql<DB>>> std::ops::Add<Rhs> for $ty {
+				type Output = ArithmeticCombo<DB, Self, Rhs>;
+
+				fn add(self, rhs: Rhs) -> Self::Output {
+					super::SqlExpression::plus(self, rhs)
+				}
+			}
+
+			impl<DB: Database, $($param: Into<Sql<DB>>,)* Rhs: Into<Sql<DB>>> std::ops::Sub<Rhs> for $ty {
+				type Output = ArithmeticCombo<DB, Self, Rhs>;
+
+				fn sub(self, rhs: Rhs) -> Self::Output {
+					super::SqlExpression::minus(self, rhs)
+				}
+			}
+
+			impl<DB: Database, $($param: Into<Sql<DB>>,)* Rhs: Into<Sql<DB>>> std::ops::Mul<Rhs> for $ty {
+				type Output = ArithmeticCombo<DB, Self, Rhs>;
+
+				fn mul(self, rhs: Rhs) -> Self::Output {
+					super::SqlExpression::times(self, rhs)
+				}
+			}
+
+			impl<DB: Database, $($param: Into<Sql<DB>>,)* Rhs: Into<Sql<DB>>> std::ops::Div<Rhs> for $ty {
+				type Output = ArithmeticCombo<DB, Self, Rhs>;
+
+				fn div(self, rhs: Rhs) -> Self::Output {
+					super::SqlExpression::divided_by(self, rhs)
+				}
+			}
+
+			impl<DB: Database, $($param: Into<Sql<DB>>,)* Rhs: Into<Sql<DB>>> std::ops::Rem<Rhs> for $ty {
+				type Output = ArithmeticCombo<DB, Self, Rhs>;
+
+				fn rem(self, rhs: Rhs) -> Self::Output {
+					super::SqlExpression::modulo(self, rhs)
+				}
+			}
+
+			impl<DB: Database, $($param: Into<Sql<DB>>,)*> std::ops::Neg for $ty {
+				type Output = Negation<DB, Self>;
+
+				fn neg(self) -> Self::Output {
+					super::SqlExpression::negate(self)
+				}
+			}
+		)+
+	};
+}
+
+impl_arithmetic_ops!(
+	[] super::ColumnReference<DB>,
+	[] super::TableAndColumnReference<DB>,
+	[L, R] ArithmeticCombo<DB, L, R>,
+	[T] Negation<DB, T>,
+	[T] Parenthesis<DB, T>,
+	[] Case<DB>,
+	[] ScalarSubquery<DB>,
+	[] super::function::ast::Abs<DB>,
+	[] super::function::ast::Count<DB>,
+	[] super::function::ast::CountDistinct<DB>,
+	[] super::function::ast::Min<DB>,
+	[] super::function::ast::Max<DB>,
+	[] super::function::ast::Coalesce<DB>,
+	[] super::function::ast::DateDiff<DB>,
+	[] super::function::ast::Over<DB>,
+);
@@ -2,20 +2,96 @@ use std::marker::PhantomData;
 
 use crate::{Database, IntoRawSql, Sql};
 
+/// A table name, optionally qualified by a schema (or a database, on mysql).
+///
+/// Converts from a plain name (i.e. `"sales"`), or a `(schema, name)` tuple
+/// (i.e. `("reporting", "sales")`, rendered as `"reporting"."sales"`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableName {
+	pub(crate) schema: Option<String>,
+	pub(crate) name: String,
+}
+
+impl TableName {
+	pub fn new<N: Into<String>>(name: N) -> Self {
+		Self {
+			schema: None,
+			name: name.into(),
+		}
+	}
+
+	pub fn with_schema<S: Into<String>, N: Into<String>>(schema: S, name: N) -> Self {
+		Self {
+			schema: Some(schema.into()),
+			name: name.into(),
+		}
+	}
+
+	/// Quotes each part of the name separately.
+	pub(crate) fn quote<DB: Database>(&self) -> String {
+		match &self.schema {
+			Some(schema) => {
+				DB::sql_quote_identifier(schema.as_str())
+					+ "." + &DB::sql_quote_identifier(self.name.as_str())
+			}
+			None => DB::sql_quote_identifier(self.name.as_str()),
+		}
+	}
+}
+
+impl From<&str> for TableName {
+	fn from(name: &str) -> Self {
+		Self::new(name)
+	}
+}
+
+impl From<String> for TableName {
+	fn from(name: String) -> Self {
+		Self::new(name)
+	}
+}
+
+impl From<&String> for TableName {
+	fn from(name: &String) -> Self {
+		Self::new(name.as_str())
+	}
+}
+
+impl<S: Into<String>, N: Into<String>> From<(S, N)> for TableName {
+	fn from((schema, name): (S, N)) -> Self {
+		Self::with_schema(schema, name)
+	}
+}
+
 pub struct TableAndColumnReference<DB: Database> {
 	pub(crate) db: PhantomData<DB>,
-	pub(crate) table_name: String,
+	pub(crate) table_name: TableName,
 	pub(crate) column_name: String,
 }
 
 impl<DB: Database> TableAndColumnReference<DB> {
-	pub fn new<T: Into<String>, C: Into<String>>(table_name: T, column_name: C) -> Self {
+	/// A `(schema, name)` tuple references a column of a schema-qualified table
+	/// (i.e. `"schema"."table"."column"`).
+	pub fn new<T: Into<TableName>, C: Into<String>>(table_name: T, column_name: C) -> Self {
 		Self {
 			db: Default::default(),
 			table_name: table_name.into(),
 			column_name: column_name.into(),
 		}
 	}
+
+	/// References a column of a schema-qualified table (i.e. `"schema"."table"."column"`).
+	pub fn with_schema<S: Into<String>, T: Into<String>, C: Into<String>>(
+		schema: S,
+		table_name: T,
+		column_name: C,
+	) -> Self {
+		Self {
+			db: Default::default(),
+			table_name: TableName::with_schema(schema, table_name),
+			column_name: column_name.into(),
+		}
+	}
 }
 
 impl<DB: Database> From<TableAndColumnReference<DB>> for Sql<DB> {
@@ -26,18 +102,17 @@ impl<DB: Database> From<TableAndColumnReference<DB>> for Sql<DB> {
 			column_name,
 		} = expr;
 
-		(DB::sql_quote_identifier(table_name) + "." + &DB::sql_quote_identifier(column_name))
-			.into_raw_sql()
+		(table_name.quote::<DB>() + "." + &DB::sql_quote_identifier(column_name)).into_raw_sql()
 	}
 }
 
 pub struct TableReference<DB: Database> {
 	pub(crate) db: PhantomData<DB>,
-	pub(crate) table_name: String,
+	pub(crate) table_name: TableName,
 }
 
 impl<DB: Database> TableReference<DB> {
-	pub fn new<C: Into<String>>(table_name: C) -> Self {
+	pub fn new<N: Into<TableName>>(table_name: N) -> Self {
 		Self {
 			db: Default::default(),
 			table_name: table_name.into(),
@@ -49,7 +124,7 @@ impl<DB: Database> From<TableReference<DB>> for Sql<DB> {
 	fn from(expr: TableReference<DB>) -> Self {
 		let TableReference { db: _, table_name } = expr;
 
-		DB::sql_quote_identifier(table_name).into_raw_sql()
+		table_name.quote::<DB>().into_raw_sql()
 	}
 }
 
@@ -66,15 +141,11 @@ impl<DB: Database> ColumnReference<DB> {
 		}
 	}
 
-	pub fn with_table<T: Into<String>, C: Into<String>>(
+	pub fn with_table<T: Into<TableName>, C: Into<String>>(
 		table_name: T,
 		column_name: C,
 	) -> TableAndColumnReference<DB> {
-		TableAndColumnReference {
-			db: Default::default(),
-			table_name: table_name.into(),
-			column_name: column_name.into(),
-		}
+		TableAndColumnReference::new(table_name, column_name)
 	}
 }
 
@@ -2,17 +2,25 @@ use std::marker::PhantomData;
 
 pub use self::function::{
 	abs, coalesce, coalesce3, concat, concat3, count, count_distinct, current_datetime, day_diff,
-	lower, max, min, minute_diff,
+	dense_rank, first_value, lag, lead, lower, max, min, minute_diff, rank, row_number, window,
+	WindowFunction,
+};
+pub use self::grammar::{
+	all, all_array, any, any_array, case, case_when, exists, not_exists, ArithmeticOp, Case,
+	ComparisonOp, Exists, IntoSqlRange, LogicalNot, LogicalOp, Parenthesis, Quantified, Quantifier,
+	ScalarSubquery, Subquery,
 };
-pub use self::grammar::{ComparisonOp, LogicalNot, LogicalOp, Parenthesis};
 pub use self::identifier::*;
-use crate::sql_lang::expression::grammar::{IsNull, LogicalCombo};
+use crate::sql_lang::expression::grammar::{
+	ArithmeticCombo, Between, IsNull, Like, LogicalCombo, Negation,
+};
 use crate::sql_lang::Sql;
 use crate::Database;
 
 pub mod function;
 pub mod grammar;
 pub mod identifier;
+pub mod pattern;
 
 pub mod prelude {
 	pub use super::*;
@@ -94,6 +102,111 @@ pub trait SqlExpression<DB: Database>: Into<Sql<DB>> {
 		}
 	}
 
+	fn plus<T: Into<Sql<DB>>>(self, rhs: T) -> ArithmeticCombo<DB, Self, T> {
+		ArithmeticCombo {
+			db: PhantomData,
+			lhs: self,
+			op: ArithmeticOp::Add,
+			rhs,
+		}
+	}
+
+	fn minus<T: Into<Sql<DB>>>(self, rhs: T) -> ArithmeticCombo<DB, Self, T> {
+		ArithmeticCombo {
+			db: PhantomData,
+			lhs: self,
+			op: ArithmeticOp::Subtract,
+			rhs,
+		}
+	}
+
+	fn times<T: Into<Sql<DB>>>(self, rhs: T) -> ArithmeticCombo<DB, Self, T> {
+		ArithmeticCombo {
+			db: PhantomData,
+			lhs: self,
+			op: ArithmeticOp::Multiply,
+			rhs,
+		}
+	}
+
+	/// Note that dividing integers truncates the result on postgres and sqlite, but not on mysql.
+	fn divided_by<T: Into<Sql<DB>>>(self, rhs: T) -> ArithmeticCombo<DB, Self, T> {
+		ArithmeticCombo {
+			db: PhantomData,
+			lhs: self,
+			op: ArithmeticOp::Divide,
+			rhs,
+		}
+	}
+
+	fn modulo<T: Into<Sql<DB>>>(self, rhs: T) -> ArithmeticCombo<DB, Self, T> {
+		ArithmeticCombo {
+			db: PhantomData,
+			lhs: self,
+			op: ArithmeticOp::Modulo,
+			rhs,
+		}
+	}
+
+	fn negate(self) -> Negation<DB, Self> {
+		Negation {
+			db: PhantomData,
+			content: self,
+		}
+	}
+
+	/// Tests whether the value is within a range, including both bounds (i.e. `between`).
+	fn between<L: Into<Sql<DB>>, H: Into<Sql<DB>>>(self, low: L, high: H) -> Between<DB> {
+		Between {
+			lhs: self.into(),
+			low: low.into(),
+			high: high.into(),
+			not: false,
+		}
+	}
+
+	fn not_between<L: Into<Sql<DB>>, H: Into<Sql<DB>>>(self, low: L, high: H) -> Between<DB> {
+		Between {
+			lhs: self.into(),
+			low: low.into(),
+			high: high.into(),
+			not: true,
+		}
+	}
+
+	/// Matches against a pattern, with `%` and `_` wildcards (i.e. `like`).
+	///
+	/// Whether the match is case-sensitive depends on the vendor (and on mysql, the collation).
+	fn like<T: Into<Sql<DB>>>(self, pattern: T) -> Like<DB> {
+		Like {
+			lhs: self.into(),
+			pattern: pattern.into(),
+			not: false,
+			case_insensitive: false,
+		}
+	}
+
+	fn not_like<T: Into<Sql<DB>>>(self, pattern: T) -> Like<DB> {
+		Like {
+			lhs: self.into(),
+			pattern: pattern.into(),
+			not: true,
+			case_insensitive: false,
+		}
+	}
+
+	/// Matches against a pattern, ignoring case.
+	///
+	/// This renders as `ilike` on postgres, and `lower(...) like lower(...)` elsewhere.
+	fn ilike<T: Into<Sql<DB>>>(self, pattern: T) -> Like<DB> {
+		Like {
+			lhs: self.into(),
+			pattern: pattern.into(),
+			not: false,
+			case_insensitive: true,
+		}
+	}
+
 	#[allow(clippy::wrong_self_convention)]
 	fn is_null(self) -> IsNull<DB, Self> {
 		IsNull {
@@ -0,0 +1,33 @@
+//! Helpers for turning arbitrary (e.g. user-entered) text into a pattern for
+//! [like()](super::SqlExpression::like), matching the text literally.
+
+/// Escapes the `%` and `_` wildcards (and the escape character itself) in the text.
+pub fn escape_like<T: AsRef<str>>(text: T) -> String {
+	let text = text.as_ref();
+	let mut escaped = String::with_capacity(text.len());
+
+	for c in text.chars() {
+		if matches!(c, '\\' | '%' | '_') {
+			escaped.push('\\');
+		}
+
+		escaped.push(c);
+	}
+
+	escaped
+}
+
+/// A pattern matching any value containing the text (i.e. `%text%`).
+pub fn contains<T: AsRef<str>>(text: T) -> String {
+	format!("%{}%", escape_like(text))
+}
+
+/// A pattern matching any value starting with the text (i.e. `text%`).
+pub fn starts_with<T: AsRef<str>>(text: T) -> String {
+	format!("{}%", escape_like(text))
+}
+
+/// A pattern matching any value ending with the text (i.e. `%text`).
+pub fn ends_with<T: AsRef<str>>(text: T) -> String {
+	format!("%{}", escape_like(text))
+}
@@ -1,3 +1,4 @@
+use crate::sql_lang::expression::TableName;
 use crate::value::Value;
 use crate::{Database, IntoSqlValue};
 
@@ -278,7 +279,7 @@ impl<DB: Database> IntoRawSql<DB> for char {
 
 #[derive(Debug, Clone)]
 pub(crate) struct ColRef {
-	pub(crate) table_name: Option<String>,
+	pub(crate) table_name: Option<TableName>,
 	pub(crate) column_name: String,
 }
 
@@ -0,0 +1,254 @@
+use crate::error::{SyntaxError, SyntaxErrorKind};
+use crate::sql_lang::clause::{OrderBy, With};
+use crate::sql_lang::statement::select::Pagination;
+use crate::sql_lang::statement::Select;
+use crate::{Database, FrozenSql, IntoRawSql, IntoSql, Sql};
+
+/// Combines the results of multiple SELECT statements
+/// (i.e. `select ... union select ...`).
+#[derive(Debug)]
+pub struct Compound<DB: Database> {
+	pub(crate) first: Select<DB>,
+	pub(crate) members: Vec<(CompoundOperator, Select<DB>)>,
+	pub(crate) order_by_clause: Option<OrderBy<DB>>,
+	pub(crate) pagination: Pagination,
+	pub(crate) with_clause: Option<With<DB>>,
+}
+
+impl<DB: Database> Compound<DB> {
+	pub fn build(select_statement: Select<DB>) -> CompoundBuilder<DB, false, false> {
+		CompoundBuilder {
+			first: select_statement,
+			members: vec![],
+			order_by_clause: None,
+			pagination: Pagination::default(),
+			with_clause: None,
+		}
+	}
+}
+
+impl<DB: Database> Clone for Compound<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			first: self.first.clone(),
+			members: self.members.clone(),
+			order_by_clause: self.order_by_clause.clone(),
+			pagination: self.pagination,
+			with_clause: self.with_clause.clone(),
+		}
+	}
+}
+
+impl<DB: Database> From<Compound<DB>> for Sql<DB> {
+	fn from(statement: Compound<DB>) -> Self {
+		let Compound {
+			first,
+			members,
+			order_by_clause,
+			pagination,
+			with_clause,
+		} = statement;
+
+		let mut sql: Sql<DB> = "".into_raw_sql();
+
+		if let Some(with_clause) = with_clause {
+			sql = sql.append(with_clause).raw_append(' ');
+		}
+
+		// members are not parenthesized, as sqlite does not allow it
+		sql = sql.append(first);
+
+		for (operator, select_statement) in members {
+			sql = sql.raw_append(match operator {
+				CompoundOperator::Union => " union ",
+				CompoundOperator::UnionAll => " union all ",
+				CompoundOperator::Intersect => " intersect ",
+				CompoundOperator::Except => " except ",
+			});
+
+			sql = sql.append(select_statement);
+		}
+
+		if let Some(order_by_clause) = order_by_clause {
+			sql = sql.raw_append(' ').append(order_by_clause);
+		}
+
+		pagination.render(sql)
+	}
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompoundOperator {
+	Union,
+	UnionAll,
+	Intersect,
+	Except,
+}
+
+pub struct CompoundBuilder<DB: Database, const HAS_MEMBERS: bool, const HAS_ORDER_BY: bool> {
+	first: Select<DB>,
+	members: Vec<(CompoundOperator, Select<DB>)>,
+	order_by_clause: Option<Result<OrderBy<DB>, SyntaxError>>,
+	pagination: Pagination,
+	with_clause: Option<With<DB>>,
+}
+
+impl<DB: Database, const HAS_MEMBERS: bool, const HAS_ORDER_BY: bool>
+	CompoundBuilder<DB, HAS_MEMBERS, HAS_ORDER_BY>
+{
+	fn combine(
+		mut self,
+		operator: CompoundOperator,
+		select_statement: Select<DB>,
+	) -> CompoundBuilder<DB, true, HAS_ORDER_BY> {
+		self.members.push((operator, select_statement));
+
+		CompoundBuilder {
+			first: self.first,
+			members: self.members,
+			order_by_clause: self.order_by_clause,
+			pagination: self.pagination,
+			with_clause: self.with_clause,
+		}
+	}
+
+	/// Combines the rows of both statements, removing duplicates.
+	pub fn union(self, select_statement: Select<DB>) -> CompoundBuilder<DB, true, HAS_ORDER_BY> {
+		self.combine(CompoundOperator::Union, select_statement)
+	}
+
+	/// Combines the rows of both statements, keeping duplicates.
+	pub fn union_all(
+		self,
+		select_statement: Select<DB>,
+	) -> CompoundBuilder<DB, true, HAS_ORDER_BY> {
+		self.combine(CompoundOperator::UnionAll, select_statement)
+	}
+
+	/// Keeps only the rows that are also produced by the specified statement.
+	///
+	/// Vendors disagree on the precedence of INTERSECT over UNION and EXCEPT, and members cannot be
+	/// parenthesized on sqlite. Combining them produces a
+	/// [MixedCompoundOperators](SyntaxErrorKind::MixedCompoundOperators) error when finalized;
+	/// use a [CompoundSubquery](crate::sql_lang::clause::sql_join::TableReferenceKind::CompoundSubquery)
+	/// instead.
+	pub fn intersect(
+		self,
+		select_statement: Select<DB>,
+	) -> CompoundBuilder<DB, true, HAS_ORDER_BY> {
+		self.combine(CompoundOperator::Intersect, select_statement)
+	}
+
+	/// Removes the rows that are also produced by the specified statement.
+	pub fn except(self, select_statement: Select<DB>) -> CompoundBuilder<DB, true, HAS_ORDER_BY> {
+		self.combine(CompoundOperator::Except, select_statement)
+	}
+
+	/// Prefixes the compound query with common table expressions, which all of its members can
+	/// refer to (i.e. `with ... select ... union select ...`).
+	pub fn with_cte_clause(mut self, clause: With<DB>) -> Self {
+		self.with_clause = Some(clause);
+		self
+	}
+
+	/// Limits the number of rows retrieved by the compound query (i.e. `... limit n`).
+	pub fn limit(mut self, limit: u32) -> Self {
+		self.pagination.limit = Some(limit);
+		self.pagination.fetch_first = false;
+		self
+	}
+
+	/// Skips the specified number of rows of the compound query (i.e. `... offset n`).
+	pub fn offset(mut self, offset: u32) -> Self {
+		self.pagination.offset = Some(offset);
+		self
+	}
+
+	/// Limits the number of rows retrieved by the compound query, using the ANSI syntax
+	/// (i.e. `... offset n rows fetch first n rows only`).
+	pub fn fetch_first(mut self, limit: u32) -> Self {
+		self.pagination.limit = Some(limit);
+		self.pagination.fetch_first = true;
+		self
+	}
+}
+
+impl<DB: Database, const HAS_MEMBERS: bool> CompoundBuilder<DB, HAS_MEMBERS, false> {
+	/// Orders the rows of the compound query.
+	pub fn with_order_by_clause(
+		self,
+		order_by_clause: OrderBy<DB>,
+	) -> CompoundBuilder<DB, HAS_MEMBERS, true> {
+		CompoundBuilder {
+			first: self.first,
+			members: self.members,
+			order_by_clause: Some(Ok(order_by_clause)),
+			pagination: self.pagination,
+			with_clause: self.with_clause,
+		}
+	}
+
+	/// Orders the rows of the compound query, by the specified result columns.
+	pub fn order_by<S: IntoIterator<Item = (Col, bool)>, Col: Into<String>>(
+		self,
+		predicates: S,
+	) -> CompoundBuilder<DB, HAS_MEMBERS, true> {
+		CompoundBuilder {
+			first: self.first,
+			members: self.members,
+			order_by_clause: Some(predicates.into_iter().collect::<Vec<_>>().try_into()),
+			pagination: self.pagination,
+			with_clause: self.with_clause,
+		}
+	}
+}
+
+impl<DB: Database, const HAS_ORDER_BY: bool> CompoundBuilder<DB, true, HAS_ORDER_BY> {
+	pub fn finalize(self) -> Result<Compound<DB>, SyntaxError> {
+		// An ORDER BY or LIMIT within a member would apply to the entire compound query instead,
+		// a WITH clause is only valid before the first member, and rows of a compound query
+		// cannot be locked
+		for select_statement in
+			std::iter::once(&self.first).chain(self.members.iter().map(|(_, s)| s))
+		{
+			if select_statement.order_by_clause.is_some()
+				|| !select_statement.pagination.is_empty()
+				|| select_statement.with_clause.is_some()
+				|| !select_statement.locking.is_empty()
+			{
+				return Err(SyntaxError::new(
+					SyntaxErrorKind::InvalidCompoundMember,
+					"".to_string(),
+				));
+			}
+		}
+
+		// postgres & mysql evaluate INTERSECT first, sqlite evaluates all operators left to right
+		let intersects = self
+			.members
+			.iter()
+			.filter(|(operator, _)| *operator == CompoundOperator::Intersect)
+			.count();
+
+		if intersects > 0 && intersects < self.members.len() {
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::MixedCompoundOperators,
+				"".to_string(),
+			));
+		}
+
+		self.pagination.validate::<DB>()?;
+
+		Ok(Compound {
+			first: self.first,
+			members: self.members,
+			order_by_clause: self.order_by_clause.transpose()?,
+			pagination: self.pagination,
+			with_clause: self.with_clause,
+		})
+	}
+
+	pub fn finalize_and_freeze(self) -> Result<FrozenSql<DB>, SyntaxError> {
+		Ok(self.finalize()?.into_sql().freeze())
+	}
+}
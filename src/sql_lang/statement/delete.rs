@@ -1,20 +1,22 @@
 use crate::error::SyntaxError;
-use crate::sql_lang::clause::Where;
-use crate::sql_lang::expression::TableReference;
+use crate::sql_lang::clause::{Where, With};
+use crate::sql_lang::expression::{TableName, TableReference};
 use crate::value::IntoSqlValue;
 use crate::{sql_lang, Database, FrozenSql, IntoRawSql, IntoSql, Sql};
 
 #[derive(Debug)]
 pub struct Delete<DB: Database> {
-	pub(crate) table_name: String,
+	pub(crate) table_name: TableName,
 	pub(crate) where_clause: Option<sql_lang::clause::sql_where::Where<DB>>,
+	pub(crate) with_clause: Option<With<DB>>,
 }
 
 impl<DB: Database> Delete<DB> {
-	pub fn build<N: Into<String>>(table_name: N) -> DeleteBuilder<DB> {
+	pub fn build<N: Into<TableName>>(table_name: N) -> DeleteBuilder<DB, false> {
 		DeleteBuilder {
 			table_name: table_name.into(),
 			where_clause_builder: None,
+			with_clause: None,
 		}
 	}
 }
@@ -24,13 +26,21 @@ impl<DB: Database> Clone for Delete<DB> {
 		Self {
 			table_name: self.table_name.clone(),
 			where_clause: self.where_clause.clone(),
+			with_clause: self.with_clause.clone(),
 		}
 	}
 }
 
 impl<DB: Database> From<Delete<DB>> for Sql<DB> {
 	fn from(delete_statement: Delete<DB>) -> Self {
-		let mut sql = IntoRawSql::<DB>::into_raw_sql("delete from ")
+		let mut sql: Sql<DB> = "".into_raw_sql();
+
+		if let Some(with_clause) = delete_statement.with_clause {
+			sql = sql.append(with_clause).raw_append(' ');
+		}
+
+		sql = sql
+			.raw_append("delete from ")
 			.append(TableReference::new(delete_statement.table_name));
 
 		if let Some(where_clause) = delete_statement.where_clause {
@@ -41,38 +51,60 @@ impl<DB: Database> From<Delete<DB>> for Sql<DB> {
 	}
 }
 
-pub struct DeleteBuilder<DB: Database> {
-	table_name: String,
+pub struct DeleteBuilder<DB: Database, const HAS_WHERE: bool> {
+	table_name: TableName,
 	where_clause_builder: Option<sql_lang::clause::sql_where::WhereBuilder<DB, true, false>>,
+	with_clause: Option<With<DB>>,
 }
 
-impl<DB: Database> DeleteBuilder<DB> {
+impl<DB: Database, const HAS_WHERE: bool> DeleteBuilder<DB, HAS_WHERE> {
+	/// Prefixes the statement with common table expressions (i.e. `with ... delete ...`).
+	pub fn with_cte_clause(mut self, clause: With<DB>) -> Self {
+		self.with_clause = Some(clause);
+		self
+	}
+
 	pub fn where_column_equal_to<N: Into<String>, V: IntoSqlValue<DB>>(
-		mut self,
+		self,
 		name: N,
 		value: V,
-	) -> Self {
-		self.where_clause_builder = Some(if let Some(builder) = self.where_clause_builder {
-			builder.and_column_equal_to(name, value)
-		} else {
-			Where::build().column_equal_to(name, value)
-		});
-
-		self
+	) -> DeleteBuilder<DB, true> {
+		DeleteBuilder {
+			table_name: self.table_name,
+			where_clause_builder: Some(if let Some(builder) = self.where_clause_builder {
+				builder.and_column_equal_to(name, value)
+			} else {
+				Where::build().column_equal_to(name, value)
+			}),
+			with_clause: self.with_clause,
+		}
 	}
 
-	pub fn with_where_clause(mut self, clause: sql_lang::clause::Where<DB>) -> Self {
-		self.where_clause_builder = Some(if let Some(builder) = self.where_clause_builder {
-			builder.merge_with_clause(clause)
-		} else {
-			clause.into_builder()
-		});
+	pub fn with_where_clause(self, clause: sql_lang::clause::Where<DB>) -> DeleteBuilder<DB, true> {
+		DeleteBuilder {
+			table_name: self.table_name,
+			where_clause_builder: Some(if let Some(builder) = self.where_clause_builder {
+				builder.merge_with_clause(clause)
+			} else {
+				clause.into_builder()
+			}),
+			with_clause: self.with_clause,
+		}
+	}
+}
 
-		self
+impl<DB: Database> DeleteBuilder<DB, false> {
+	/// Explicitly deletes every row in the table, in place of a WHERE clause.
+	pub fn all_rows(self) -> DeleteBuilder<DB, true> {
+		DeleteBuilder {
+			table_name: self.table_name,
+			where_clause_builder: self.where_clause_builder,
+			with_clause: self.with_clause,
+		}
 	}
 }
 
-impl<DB: Database> DeleteBuilder<DB> {
+impl<DB: Database> DeleteBuilder<DB, true> {
 	pub fn finalize(self) -> Result<Delete<DB>, SyntaxError> {
 		Ok(Delete {
 			table_name: self.table_name,
@@ -80,6 +112,7 @@ impl<DB: Database> DeleteBuilder<DB> {
 				.where_clause_builder
 				.map(|w| w.finalize())
 				.transpose()?,
+			with_clause: self.with_clause,
 		})
 	}
 
@@ -1,19 +1,23 @@
-use crate::error::SyntaxError;
-use crate::sql_lang::expression::{ColumnReference, TableReference};
+use crate::error::{SyntaxError, SyntaxErrorKind};
+use crate::sql_lang::expression::{ColumnReference, TableName, TableReference};
+use crate::sql_lang::statement::select::SelectPredicateKind;
+use crate::sql_lang::statement::Select;
 use crate::value::{IntoSqlValue, Value};
 use crate::{Database, FrozenSql, IntoRawSql, IntoSql, Sql};
 
 #[derive(Debug)]
 pub struct Insert<DB: Database> {
-	pub(crate) table_name: String,
+	pub(crate) table_name: TableName,
 	pub(crate) pairs: Vec<(String, Option<Value<DB>>)>,
+	pub(crate) select: Option<(Vec<String>, Select<DB>)>,
 }
 
 impl<DB: Database> Insert<DB> {
-	pub fn build<N: Into<String>>(table_name: N) -> InsertBuilder<DB> {
+	pub fn build<N: Into<TableName>>(table_name: N) -> InsertBuilder<DB> {
 		InsertBuilder {
 			table_name: table_name.into(),
 			pairs: vec![],
+			select: None,
 		}
 	}
 }
@@ -23,6 +27,7 @@ impl<DB: Database> Clone for Insert<DB> {
 		Self {
 			table_name: self.table_name.clone(),
 			pairs: self.pairs.clone(),
+			select: self.select.clone(),
 		}
 	}
 }
@@ -33,6 +38,21 @@ impl<DB: Database> From<Insert<DB>> for Sql<DB> {
 		sql = sql.append(TableReference::new(insert_statement.table_name));
 		sql = sql.raw_append(" (");
 
+		if let Some((column_names, select_statement)) = insert_statement.select {
+			for (i, name) in column_names.into_iter().enumerate() {
+				if i > 0 {
+					sql = sql.raw_append(',');
+				}
+
+				sql = sql.append(ColumnReference::new(name));
+			}
+
+			sql = sql.raw_append(") ");
+			sql = sql.append(select_statement);
+
+			return sql;
+		}
+
 		for (i, (name, _value)) in insert_statement.pairs.iter().enumerate() {
 			if i > 0 {
 				sql = sql.raw_append(',');
@@ -58,8 +78,9 @@ impl<DB: Database> From<Insert<DB>> for Sql<DB> {
 }
 
 pub struct InsertBuilder<DB: Database> {
-	table_name: String,
+	table_name: TableName,
 	pairs: Vec<(String, Option<Value<DB>>)>,
+	select: Option<(Vec<String>, Select<DB>)>,
 }
 
 impl<DB: Database> InsertBuilder<DB> {
@@ -68,10 +89,61 @@ impl<DB: Database> InsertBuilder<DB> {
 		self
 	}
 
+	/// Inserts the rows produced by a SELECT statement, instead of individual column values.
+	///
+	/// The SELECT statement must retrieve one column/expression for each of the column names.
+	/// Common table expressions are attached to the SELECT statement, which places them where all
+	/// vendors accept them (i.e. `insert into ... with ... select ...`).
+	pub fn select<N: Into<String>, I: IntoIterator<Item = N>>(
+		mut self,
+		column_names: I,
+		select_statement: Select<DB>,
+	) -> Self {
+		self.select = Some((
+			column_names.into_iter().map(|n| n.into()).collect(),
+			select_statement,
+		));
+		self
+	}
+
 	pub fn finalize(self) -> Result<Insert<DB>, SyntaxError> {
+		if let Some((column_names, select_statement)) = &self.select {
+			if !self.pairs.is_empty() {
+				return Err(SyntaxError::new(
+					SyntaxErrorKind::ConflictingInsertSources,
+					"".to_string(),
+				));
+			}
+
+			if column_names.is_empty() {
+				return Err(SyntaxError::new(
+					SyntaxErrorKind::MissingInsertColumns,
+					"".to_string(),
+				));
+			}
+
+			// the number of columns retrieved by `*` is only known to the database
+			let selects_all = select_statement
+				.select_columns
+				.iter()
+				.any(|c| matches!(c, SelectPredicateKind::All(_)));
+
+			if !selects_all && select_statement.select_columns.len() != column_names.len() {
+				return Err(SyntaxError::new(
+					SyntaxErrorKind::MismatchedInsertRowLength,
+					format!(
+						"select retrieves {} columns, expected {}",
+						select_statement.select_columns.len(),
+						column_names.len()
+					),
+				));
+			}
+		}
+
 		Ok(Insert {
 			table_name: self.table_name,
 			pairs: self.pairs,
+			select: self.select,
 		})
 	}
 
@@ -0,0 +1,198 @@
+use crate::error::{SyntaxError, SyntaxErrorKind};
+use crate::sql_lang::expression::{ColumnReference, TableName, TableReference};
+use crate::value::{IntoSqlValue, Value};
+use crate::{Database, FrozenSql, IntoRawSql, Sql};
+
+/// An INSERT statement for multiple rows, sharing a single column list.
+#[derive(Debug)]
+pub struct InsertRows<DB: Database> {
+	pub(crate) table_name: TableName,
+	pub(crate) column_names: Vec<String>,
+	pub(crate) rows: Vec<Vec<Option<Value<DB>>>>,
+}
+
+impl<DB: Database> InsertRows<DB> {
+	pub fn build<N: Into<TableName>, C: Into<String>, I: IntoIterator<Item = C>>(
+		table_name: N,
+		column_names: I,
+	) -> InsertRowsBuilder<DB, false> {
+		InsertRowsBuilder {
+			table_name: table_name.into(),
+			column_names: column_names.into_iter().map(|c| c.into()).collect(),
+			rows: vec![],
+		}
+	}
+
+	/// Converts the statement into one or more statements, splitting the rows as required so that
+	/// no statement exceeds the vendor's bind-parameter limit.
+	pub fn into_statements(self) -> Vec<Sql<DB>> {
+		let rows_per_statement =
+			(DB::sql_bind_parameter_limit() / self.column_names.len().max(1)).max(1);
+
+		let mut statements = vec![];
+		let mut rows = self.rows.into_iter().peekable();
+
+		while rows.peek().is_some() {
+			statements.push(render(
+				&self.table_name,
+				&self.column_names,
+				rows.by_ref().take(rows_per_statement),
+			));
+		}
+
+		statements
+	}
+}
+
+impl<DB: Database> Clone for InsertRows<DB> {
+	fn clone(&self) -> Self {
+		Self {
+			table_name: self.table_name.clone(),
+			column_names: self.column_names.clone(),
+			rows: self.rows.clone(),
+		}
+	}
+}
+
+/// Renders all rows as a single statement, regardless of the vendor's bind-parameter limit.
+///
+/// See [InsertRows::into_statements()] for a conversion which respects the limit.
+impl<DB: Database> From<InsertRows<DB>> for Sql<DB> {
+	fn from(insert_statement: InsertRows<DB>) -> Self {
+		render(
+			&insert_statement.table_name,
+			&insert_statement.column_names,
+			insert_statement.rows,
+		)
+	}
+}
+
+fn render<DB: Database, R: IntoIterator<Item = Vec<Option<Value<DB>>>>>(
+	table_name: &TableName,
+	column_names: &[String],
+	rows: R,
+) -> Sql<DB> {
+	let mut sql: Sql<DB> = "insert into ".into_raw_sql();
+	sql = sql.append(TableReference::new(table_name.clone()));
+	sql = sql.raw_append(" (");
+
+	for (i, name) in column_names.iter().enumerate() {
+		if i > 0 {
+			sql = sql.raw_append(',');
+		}
+
+		sql = sql.append(ColumnReference::new(name));
+	}
+
+	sql = sql.raw_append(") values ");
+
+	for (i, row) in rows.into_iter().enumerate() {
+		if i > 0 {
+			sql = sql.raw_append(',');
+		}
+
+		sql = sql.raw_append('(');
+
+		for (j, value) in row.into_iter().enumerate() {
+			if j > 0 {
+				sql = sql.raw_append(',');
+			}
+
+			sql = sql.append(value);
+		}
+
+		sql = sql.raw_append(')');
+	}
+
+	sql
+}
+
+pub struct InsertRowsBuilder<DB: Database, const HAS_ROWS: bool> {
+	table_name: TableName,
+	column_names: Vec<String>,
+	rows: Vec<Vec<Option<Value<DB>>>>,
+}
+
+impl<DB: Database, const HAS_ROWS: bool> InsertRowsBuilder<DB, HAS_ROWS> {
+	/// Adds a row of values, in the same order as the column list.
+	pub fn row<V: IntoSqlValue<DB>, I: IntoIterator<Item = V>>(
+		mut self,
+		values: I,
+	) -> InsertRowsBuilder<DB, true> {
+		self.rows
+			.push(values.into_iter().map(|v| v.into_sql_value()).collect());
+
+		InsertRowsBuilder {
+			table_name: self.table_name,
+			column_names: self.column_names,
+			rows: self.rows,
+		}
+	}
+
+	/// Adds multiple rows of values, each in the same order as the column list.
+	pub fn rows<V: IntoSqlValue<DB>, I: IntoIterator<Item = V>, R: IntoIterator<Item = I>>(
+		mut self,
+		rows: R,
+	) -> InsertRowsBuilder<DB, true> {
+		self.rows.extend(
+			rows.into_iter()
+				.map(|values| values.into_iter().map(|v| v.into_sql_value()).collect()),
+		);
+
+		InsertRowsBuilder {
+			table_name: self.table_name,
+			column_names: self.column_names,
+			rows: self.rows,
+		}
+	}
+}
+
+impl<DB: Database> InsertRowsBuilder<DB, true> {
+	pub fn finalize(self) -> Result<InsertRows<DB>, SyntaxError> {
+		if self.column_names.is_empty() {
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::MissingInsertColumns,
+				"".to_string(),
+			));
+		}
+
+		if self.rows.is_empty() {
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::MissingInsertRows,
+				"".to_string(),
+			));
+		}
+
+		if let Some(i) = self
+			.rows
+			.iter()
+			.position(|row| row.len() != self.column_names.len())
+		{
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::MismatchedInsertRowLength,
+				format!(
+					"row {} has {} values, expected {}",
+					i,
+					self.rows[i].len(),
+					self.column_names.len()
+				),
+			));
+		}
+
+		Ok(InsertRows {
+			table_name: self.table_name,
+			column_names: self.column_names,
+			rows: self.rows,
+		})
+	}
+
+	/// Finalizes the statement, splitting it as described in [InsertRows::into_statements()].
+	pub fn finalize_and_freeze(self) -> Result<Vec<FrozenSql<DB>>, SyntaxError> {
+		Ok(self
+			.finalize()?
+			.into_statements()
+			.into_iter()
+			.map(|sql| sql.freeze())
+			.collect())
+	}
+}
@@ -1,9 +1,13 @@
+pub use compound::Compound;
 pub use delete::Delete;
 pub use insert::Insert;
+pub use insert_rows::InsertRows;
 pub use select::Select;
 pub use update::Update;
 
+pub mod compound;
 pub mod delete;
 pub mod insert;
+pub mod insert_rows;
 pub mod select;
 pub mod update;
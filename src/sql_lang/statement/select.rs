@@ -1,10 +1,13 @@
 use crate::error::{SyntaxError, SyntaxErrorKind};
 use crate::sql_lang::clause::having::HavingBuilder;
 use crate::sql_lang::clause::sql_where::WhereBuilder;
-use crate::sql_lang::clause::{GroupBy, Having, OrderBy, SqlFrom, Where};
-use crate::sql_lang::expression::{ColumnReference, TableAndColumnReference};
+use crate::sql_lang::clause::{GroupBy, Having, OrderBy, SqlFrom, Where, With};
+use crate::sql_lang::expression::{
+	ColumnReference, TableAndColumnReference, TableName, TableReference,
+};
 use crate::sql_lang::{ColRef, Sql};
 use crate::value::IntoSqlValue;
+use crate::vendor::requirements::SqlFeature;
 use crate::{sql_lang, Database, FrozenSql, IntoRawSql, IntoSql};
 
 #[derive(Debug)]
@@ -15,10 +18,14 @@ pub struct Select<DB: Database> {
 	pub(crate) group_by_clause: Option<sql_lang::clause::GroupBy<DB>>,
 	pub(crate) having_clause: Option<sql_lang::clause::Having<DB>>,
 	pub(crate) order_by_clause: Option<sql_lang::clause::OrderBy<DB>>,
+	pub(crate) distinct: Option<Distinct>,
+	pub(crate) with_clause: Option<With<DB>>,
+	pub(crate) pagination: Pagination,
+	pub(crate) locking: Locking,
 }
 
 impl<DB: Database> Select<DB> {
-	pub fn build<N: Into<String>>(
+	pub fn build<N: Into<TableName>>(
 		table_name: N,
 	) -> SelectBuilder<DB, false, false, false, false, false> {
 		SelectBuilder {
@@ -28,6 +35,10 @@ impl<DB: Database> Select<DB> {
 			group_by_clause: None,
 			having_clause_builder: None,
 			order_by_clause: None,
+			distinct: None,
+			with_clause: None,
+			pagination: Pagination::default(),
+			locking: Locking::default(),
 		}
 	}
 
@@ -41,6 +52,10 @@ impl<DB: Database> Select<DB> {
 			group_by_clause: None,
 			having_clause_builder: None,
 			order_by_clause: None,
+			distinct: None,
+			with_clause: None,
+			pagination: Pagination::default(),
+			locking: Locking::default(),
 		}
 	}
 }
@@ -54,6 +69,10 @@ impl<DB: Database> Clone for Select<DB> {
 			group_by_clause: self.group_by_clause.clone(),
 			having_clause: self.having_clause.clone(),
 			order_by_clause: self.order_by_clause.clone(),
+			distinct: self.distinct.clone(),
+			with_clause: self.with_clause.clone(),
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }
@@ -67,9 +86,52 @@ impl<DB: Database> From<Select<DB>> for Sql<DB> {
 			group_by_clause,
 			having_clause,
 			order_by_clause,
+			distinct,
+			with_clause,
+			pagination,
+			locking,
 		} = statement;
 
-		let mut sql: Sql<DB> = "select ".into_raw_sql();
+		let mut sql: Sql<DB> = "".into_raw_sql();
+
+		if let Some(with_clause) = with_clause {
+			sql = sql.append(with_clause).raw_append(' ');
+		}
+
+		sql = sql.raw_append("select ");
+
+		match distinct {
+			Some(Distinct::All) => {
+				sql = sql.raw_append("distinct ");
+			}
+
+			Some(Distinct::On(columns)) => {
+				sql = sql.raw_append("distinct on (");
+
+				for (
+					i,
+					ColRef {
+						table_name,
+						column_name,
+					},
+				) in columns.into_iter().enumerate()
+				{
+					if i > 0 {
+						sql = sql.raw_append(", ");
+					}
+
+					if let Some(table_name) = table_name {
+						sql = sql.append(TableAndColumnReference::new(table_name, column_name));
+					} else {
+						sql = sql.append(ColumnReference::new(column_name));
+					}
+				}
+
+				sql = sql.raw_append(") ");
+			}
+
+			None => {}
+		}
 
 		for (i, predicate_kind) in select_columns.into_iter().enumerate() {
 			match predicate_kind {
@@ -110,6 +172,18 @@ impl<DB: Database> From<Select<DB>> for Sql<DB> {
 					sql = sql.raw_append(" as ");
 					sql = sql.append(ColumnReference::new(alias));
 				}
+
+				SelectPredicateKind::All(table_name) => {
+					if i > 0 {
+						sql = sql.raw_append(", ");
+					}
+
+					if let Some(table_name) = table_name {
+						sql = sql.append(TableReference::new(table_name)).raw_append('.');
+					}
+
+					sql = sql.raw_append('*');
+				}
 			}
 		}
 
@@ -131,7 +205,7 @@ impl<DB: Database> From<Select<DB>> for Sql<DB> {
 			sql = sql.raw_append(' ').append(order_by_clause);
 		}
 
-		sql
+		locking.render(pagination.render(sql))
 	}
 }
 
@@ -149,6 +223,10 @@ pub struct SelectBuilder<
 	group_by_clause: Option<Result<crate::sql_lang::clause::GroupBy<DB>, SyntaxError>>,
 	having_clause_builder: Option<sql_lang::clause::having::HavingBuilder<DB, true, HAS_JOIN>>,
 	order_by_clause: Option<Result<crate::sql_lang::clause::OrderBy<DB>, SyntaxError>>,
+	distinct: Option<Distinct>,
+	with_clause: Option<With<DB>>,
+	pagination: Pagination,
+	locking: Locking,
 }
 
 impl<
@@ -179,6 +257,33 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
+		}
+	}
+
+	/// Retrieves all columns of all tables in the FROM clause (i.e. `select *`).
+	///
+	/// Must be the first column/expression retrieved, as mysql rejects `*` anywhere else. Otherwise,
+	/// a [MisplacedSelectAll](SyntaxErrorKind::MisplacedSelectAll) error is produced when finalized.
+	pub fn select_all(
+		mut self,
+	) -> SelectBuilder<DB, true, HAS_GROUP_BY, HAS_HAVING, HAS_ORDER_BY, HAS_JOIN> {
+		self.select_columns.push(SelectPredicateKind::All(None));
+
+		SelectBuilder {
+			from_clause: self.from_clause,
+			select_columns: self.select_columns,
+			where_clause_builder: self.where_clause_builder,
+			group_by_clause: self.group_by_clause,
+			having_clause_builder: self.having_clause_builder,
+			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
@@ -191,6 +296,81 @@ impl<
 
 		self
 	}
+
+	/// Prefixes the statement with common table expressions (i.e. `with ... select ...`).
+	pub fn with_cte_clause(mut self, clause: With<DB>) -> Self {
+		self.with_clause = Some(clause);
+		self
+	}
+
+	/// Removes duplicate rows from the result set (i.e. `select distinct ...`).
+	pub fn distinct(mut self) -> Self {
+		self.distinct = Some(Distinct::All);
+		self
+	}
+
+	/// Limits the number of rows retrieved (i.e. `... limit n`).
+	pub fn limit(mut self, limit: u32) -> Self {
+		self.pagination.limit = Some(limit);
+		self.pagination.fetch_first = false;
+		self
+	}
+
+	/// Skips the specified number of rows (i.e. `... offset n`).
+	///
+	/// An offset without a limit is only supported on postgres. Other vendors produce an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized.
+	pub fn offset(mut self, offset: u32) -> Self {
+		self.pagination.offset = Some(offset);
+		self
+	}
+
+	/// Limits the number of rows retrieved, using the ANSI syntax
+	/// (i.e. `... offset n rows fetch first n rows only`).
+	///
+	/// Only supported on postgres. Other vendors produce an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized.
+	pub fn fetch_first(mut self, limit: u32) -> Self {
+		self.pagination.limit = Some(limit);
+		self.pagination.fetch_first = true;
+		self
+	}
+
+	/// Locks the retrieved rows against updates by other transactions, until the end of the
+	/// current transaction (i.e. `... for update`).
+	///
+	/// Row locking is not supported on sqlite, which produces an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized.
+	pub fn for_update(mut self) -> Self {
+		self.locking.mode = Some(LockMode::Update);
+		self
+	}
+
+	/// Locks the retrieved rows against updates by other transactions, while still allowing
+	/// other shared locks (i.e. `... for share`, or `... lock in share mode` on mysql).
+	///
+	/// Row locking is not supported on sqlite, which produces an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized.
+	pub fn for_share(mut self) -> Self {
+		self.locking.mode = Some(LockMode::Share);
+		self
+	}
+
+	/// Fails immediately if a row is already locked, instead of waiting (i.e. `... nowait`).
+	///
+	/// Requires [for_update()](Self::for_update) or [for_share()](Self::for_share).
+	pub fn nowait(mut self) -> Self {
+		self.locking.wait = LockWait::NoWait;
+		self
+	}
+
+	/// Skips the rows which are already locked, instead of waiting (i.e. `... skip locked`).
+	///
+	/// Requires [for_update()](Self::for_update) or [for_share()](Self::for_share).
+	pub fn skip_locked(mut self) -> Self {
+		self.locking.wait = LockWait::SkipLocked;
+		self
+	}
 }
 
 impl<DB: Database, const HAS_COLUMNS: bool, const HAS_ORDER_BY: bool, const HAS_JOIN: bool>
@@ -213,6 +393,10 @@ impl<DB: Database, const HAS_COLUMNS: bool, const HAS_ORDER_BY: bool, const HAS_
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }
@@ -285,6 +469,10 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
@@ -309,6 +497,10 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
@@ -334,6 +526,10 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
@@ -363,9 +559,34 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
+	/// Keeps only the first row of each set of rows where the specified columns are equal
+	/// (i.e. `select distinct on (...) ...`).
+	///
+	/// Only supported on postgres. Other vendors produce an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized. An empty
+	/// list of columns produces a [MissingDistinctOnColumns](SyntaxErrorKind::MissingDistinctOnColumns)
+	/// error.
+	pub fn distinct_on<N: Into<String>, S: IntoIterator<Item = N>>(mut self, names: S) -> Self {
+		self.distinct = Some(Distinct::On(
+			names
+				.into_iter()
+				.map(|name| ColRef {
+					table_name: None,
+					column_name: name.into(),
+				})
+				.collect(),
+		));
+
+		self
+	}
+
 	pub fn where_column_equal_to<N: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		name: N,
@@ -405,7 +626,32 @@ impl<
 		const HAS_ORDER_BY: bool,
 	> SelectBuilder<DB, HAS_COLUMNS, HAS_GROUP_BY, HAS_HAVING, HAS_ORDER_BY, true>
 {
-	pub fn select_column<T: Into<String>, C: Into<String>>(
+	/// Retrieves all columns of the specified table, or alias (i.e. `select "table".*`).
+	///
+	/// A `(schema, name)` tuple retrieves the columns of a schema-qualified table
+	/// (i.e. `select "schema"."table".*`).
+	pub fn select_all_from<T: Into<TableName>>(
+		mut self,
+		table_name: T,
+	) -> SelectBuilder<DB, true, HAS_GROUP_BY, HAS_HAVING, HAS_ORDER_BY, true> {
+		self.select_columns
+			.push(SelectPredicateKind::All(Some(table_name.into())));
+
+		SelectBuilder {
+			from_clause: self.from_clause,
+			select_columns: self.select_columns,
+			where_clause_builder: self.where_clause_builder,
+			group_by_clause: self.group_by_clause,
+			having_clause_builder: self.having_clause_builder,
+			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
+		}
+	}
+
+	pub fn select_column<T: Into<TableName>, C: Into<String>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -426,10 +672,14 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
-	pub fn select_column_with_alias<T: Into<String>, C: Into<String>, A: Into<String>>(
+	pub fn select_column_with_alias<T: Into<TableName>, C: Into<String>, A: Into<String>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -451,10 +701,14 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
-	pub fn select_columns<T: Into<String>, C: Into<String>, S: IntoIterator<Item = (T, C)>>(
+	pub fn select_columns<T: Into<TableName>, C: Into<String>, S: IntoIterator<Item = (T, C)>>(
 		mut self,
 		pairs: S,
 	) -> SelectBuilder<DB, true, HAS_GROUP_BY, HAS_HAVING, HAS_ORDER_BY, true> {
@@ -476,11 +730,15 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
 	pub fn select_columns_with_alias<
-		T: Into<String>,
+		T: Into<TableName>,
 		C: Into<String>,
 		A: Into<String>,
 		S: IntoIterator<Item = (T, C, A)>,
@@ -506,6 +764,10 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
@@ -513,7 +775,7 @@ impl<
 	/// e.g. Selecting column "foo" from table "b", results in "SELECT b.foo as b_foo".
 	/// This is useful to disambiguate columns across multiple tables in a JOIN.
 	pub fn select_columns_prefixed<
-		T: Into<String>,
+		T: Into<TableName>,
 		C: Into<String>,
 		S: IntoIterator<Item = (T, C)>,
 	>(
@@ -521,9 +783,10 @@ impl<
 		pairs: S,
 	) -> SelectBuilder<DB, true, HAS_GROUP_BY, HAS_HAVING, HAS_ORDER_BY, true> {
 		for (table_name, column_name) in pairs.into_iter() {
-			let table_name = table_name.into();
+			let table_name: TableName = table_name.into();
 			let column_name = column_name.into();
-			let alias = format!("{}_{}", &table_name, &column_name);
+			// the alias is prefixed with the table name only, without its schema
+			let alias = format!("{}_{}", &table_name.name, &column_name);
 
 			self.select_columns
 				.push(SelectPredicateKind::Column(SelectedColumn {
@@ -542,10 +805,38 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 
-	pub fn where_column_equal_to<T: Into<String>, C: Into<String>, V: IntoSqlValue<DB>>(
+	/// Keeps only the first row of each set of rows where the specified columns are equal
+	/// (i.e. `select distinct on (...) ...`).
+	///
+	/// Only supported on postgres. Other vendors produce an
+	/// [UnsupportedFeature](SyntaxErrorKind::UnsupportedFeature) error when finalized. An empty
+	/// list of columns produces a [MissingDistinctOnColumns](SyntaxErrorKind::MissingDistinctOnColumns)
+	/// error.
+	pub fn distinct_on<T: Into<TableName>, C: Into<String>, S: IntoIterator<Item = (T, C)>>(
+		mut self,
+		columns: S,
+	) -> Self {
+		self.distinct = Some(Distinct::On(
+			columns
+				.into_iter()
+				.map(|(table_name, column_name)| ColRef {
+					table_name: Some(table_name.into()),
+					column_name: column_name.into(),
+				})
+				.collect(),
+		));
+
+		self
+	}
+
+	pub fn where_column_equal_to<T: Into<TableName>, C: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		table_name: T,
 		column_name: C,
@@ -596,6 +887,10 @@ impl<
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: Some(Ok(order_by_clause)),
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }
@@ -614,6 +909,10 @@ impl<DB: Database, const HAS_COLUMNS: bool, const HAS_GROUP_BY: bool, const HAS_
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: Some(predicates.into_iter().collect::<Vec<_>>().try_into()),
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }
@@ -623,7 +922,7 @@ impl<DB: Database, const HAS_COLUMNS: bool, const HAS_GROUP_BY: bool, const HAS_
 {
 	pub fn order_by<
 		S: IntoIterator<Item = (Tab, Col, bool)>,
-		Tab: Into<String>,
+		Tab: Into<TableName>,
 		Col: Into<String>,
 	>(
 		self,
@@ -636,6 +935,10 @@ impl<DB: Database, const HAS_COLUMNS: bool, const HAS_GROUP_BY: bool, const HAS_
 			group_by_clause: self.group_by_clause,
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: Some(predicates.into_iter().collect::<Vec<_>>().try_into()),
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }
@@ -659,6 +962,10 @@ impl<
 			group_by_clause: Some(Ok(group_by_clause)),
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }
@@ -677,6 +984,10 @@ impl<DB: Database, const HAS_COLUMNS: bool, const HAS_HAVING: bool, const HAS_OR
 			group_by_clause: Some(predicates.into_iter().collect::<Vec<_>>().try_into()),
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }
@@ -685,7 +996,7 @@ impl<DB: Database, const HAS_COLUMNS: bool, const HAS_HAVING: bool, const HAS_OR
 /*impl<DB: Database, const HAS_COLUMNS: bool, const HAS_HAVING: bool, const HAS_ORDER_BY: bool>
 	SelectBuilder<DB, HAS_COLUMNS, false, HAS_HAVING, HAS_ORDER_BY, true>
 {
-	pub fn group_by<S: IntoIterator<Item = (Tab, Col)>, Tab: Into<String>, Col: Into<String>>(
+	pub fn group_by<S: IntoIterator<Item = (Tab, Col)>, Tab: Into<TableName>, Col: Into<String>>(
 		self,
 		predicates: S,
 	) -> SelectBuilder<DB, HAS_COLUMNS, true, HAS_HAVING, HAS_ORDER_BY, true> {
@@ -696,6 +1007,10 @@ impl<DB: Database, const HAS_COLUMNS: bool, const HAS_HAVING: bool, const HAS_OR
 			group_by_clause: Some(predicates.into_iter().collect::<Vec<_>>().try_into()),
 			having_clause_builder: self.having_clause_builder,
 			order_by_clause: self.order_by_clause,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		}
 	}
 }*/
@@ -716,6 +1031,37 @@ impl<
 			));
 		}
 
+		if self
+			.select_columns
+			.iter()
+			.skip(1)
+			.any(|c| matches!(c, SelectPredicateKind::All(None)))
+		{
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::MisplacedSelectAll,
+				"".to_string(),
+			));
+		}
+
+		if let Some(Distinct::On(columns)) = &self.distinct {
+			if !DB::sql_supports_feature(SqlFeature::DistinctOn) {
+				return Err(SyntaxError::new(
+					SyntaxErrorKind::UnsupportedFeature,
+					"distinct on".to_string(),
+				));
+			}
+
+			if columns.is_empty() {
+				return Err(SyntaxError::new(
+					SyntaxErrorKind::MissingDistinctOnColumns,
+					"".to_string(),
+				));
+			}
+		}
+
+		self.pagination.validate::<DB>()?;
+		self.locking.validate::<DB>()?;
+
 		Ok(Select {
 			from_clause: self.from_clause,
 			select_columns: self.select_columns,
@@ -729,6 +1075,10 @@ impl<
 				.map(|w| w.finalize())
 				.transpose()?,
 			order_by_clause: self.order_by_clause.transpose()?,
+			distinct: self.distinct,
+			with_clause: self.with_clause,
+			pagination: self.pagination,
+			locking: self.locking,
 		})
 	}
 
@@ -737,10 +1087,81 @@ impl<
 	}
 }
 
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Pagination {
+	pub(crate) limit: Option<u32>,
+	pub(crate) offset: Option<u32>,
+	pub(crate) fetch_first: bool,
+}
+
+impl Pagination {
+	pub(crate) fn is_empty(&self) -> bool {
+		self.limit.is_none() && self.offset.is_none()
+	}
+
+	pub(crate) fn validate<DB: Database>(&self) -> Result<(), SyntaxError> {
+		if self.fetch_first && !DB::sql_supports_feature(SqlFeature::FetchFirst) {
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::UnsupportedFeature,
+				"fetch first".to_string(),
+			));
+		}
+
+		if self.offset.is_some()
+			&& self.limit.is_none()
+			&& !DB::sql_supports_feature(SqlFeature::OffsetWithoutLimit)
+		{
+			return Err(SyntaxError::new(
+				SyntaxErrorKind::UnsupportedFeature,
+				"offset without limit".to_string(),
+			));
+		}
+
+		Ok(())
+	}
+
+	/// Appends the limit & offset (if any) to the specified statement.
+	pub(crate) fn render<DB: Database>(self, mut sql: Sql<DB>) -> Sql<DB> {
+		if self.fetch_first {
+			if let Some(offset) = self.offset {
+				sql = sql
+					.raw_append(" offset ")
+					.append(offset)
+					.raw_append(" rows");
+			}
+
+			if let Some(limit) = self.limit {
+				sql = sql
+					.raw_append(" fetch first ")
+					.append(limit)
+					.raw_append(" rows only");
+			}
+		} else {
+			if let Some(limit) = self.limit {
+				sql = sql.raw_append(" limit ").append(limit);
+			}
+
+			if let Some(offset) = self.offset {
+				sql = sql.raw_append(" offset ").append(offset);
+			}
+		}
+
+		sql
+	}
+}
+
+#[derive(Debug, Clone)]
+pub(crate) enum Distinct {
+	All,
+	On(Vec<ColRef>),
+}
+
 #[derive(Debug)]
 pub(crate) enum SelectPredicateKind<DB: Database> {
 	Column(SelectedColumn),
 	Expression(SelectedExpression<DB>),
+	/// All columns, optionally of a single table (i.e. `*` or `"table".*`).
+	All(Option<TableName>),
 }
 
 impl<DB: Database> Clone for SelectPredicateKind<DB> {
@@ -748,6 +1169,7 @@ impl<DB: Database> Clone for SelectPredicateKind<DB> {
 		match self {
 			Self::Column(column) => Self::Column(column.clone()),
 			Self::Expression(expr) => Self::Expression(expr.clone()),
+			Self::All(table_name) => Self::All(table_name.clone()),
 		}
 	}
 }
@@ -772,3 +1194,65 @@ impl<DB: Database> Clone for SelectedExpression<DB> {
 		}
 	}
 }
+
+/// The lock taken on the rows retrieved by a SELECT statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LockMode {
+	/// Prevents other transactions from updating, deleting or locking the rows
+	/// (i.e. `for update`).
+	Update,
+	/// Prevents other transactions from updating or deleting the rows (i.e. `for share`).
+	Share,
+}
+
+/// What a locking SELECT statement does when a row is already locked by another transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum LockWait {
+	/// Waits for the other transaction to release the lock.
+	#[default]
+	Wait,
+	/// Fails immediately (i.e. `nowait`).
+	NoWait,
+	/// Leaves the locked rows out of the result set (i.e. `skip locked`).
+	SkipLocked,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Locking {
+	pub(crate) mode: Option<LockMode>,
+	pub(crate) wait: LockWait,
+}
+
+impl Locking {
+	pub(crate) fn is_empty(&self) -> bool {
+		self.mode.is_none() && self.wait == LockWait::Wait
+	}
+
+	pub(crate) fn validate<DB: Database>(&self) -> Result<(), SyntaxError> {
+		match self.mode {
+			Some(_) if !DB::sql_supports_feature(SqlFeature::RowLocking) => Err(SyntaxError::new(
+				SyntaxErrorKind::UnsupportedFeature,
+				"row locking".to_string(),
+			)),
+			None if self.wait != LockWait::Wait => Err(SyntaxError::new(
+				SyntaxErrorKind::MissingLockMode,
+				"".to_string(),
+			)),
+			_ => Ok(()),
+		}
+	}
+
+	/// Appends the locking clause (if any) to the specified statement.
+	pub(crate) fn render<DB: Database>(self, sql: Sql<DB>) -> Sql<DB> {
+		let Some(mode) = self.mode else {
+			return sql;
+		};
+
+		// unsupported row locking is rejected when the select is finalized
+		let Some(locking) = DB::sql_from_select_locking(mode, self.wait) else {
+			return sql;
+		};
+
+		sql.raw_append(' ').append(locking)
+	}
+}
@@ -1,23 +1,25 @@
 use crate::error::SyntaxError;
-use crate::sql_lang::clause::Where;
-use crate::sql_lang::expression::{ColumnReference, TableReference};
+use crate::sql_lang::clause::{Where, With};
+use crate::sql_lang::expression::{ColumnReference, TableName, TableReference};
 use crate::sql_lang::ColRef;
-use crate::value::{IntoSqlValue, Value};
+use crate::value::IntoSqlValue;
 use crate::{sql_lang, Database, FrozenSql, IntoRawSql, IntoSql, Sql};
 
 #[derive(Debug)]
 pub struct Update<DB: Database> {
-	pub(crate) table_name: String,
-	pub(crate) set_pairs: Vec<(ColRef, Option<Value<DB>>)>,
+	pub(crate) table_name: TableName,
+	pub(crate) set_pairs: Vec<(ColRef, Sql<DB>)>,
 	pub(crate) where_clause: Option<sql_lang::clause::sql_where::Where<DB>>,
+	pub(crate) with_clause: Option<With<DB>>,
 }
 
 impl<DB: Database> Update<DB> {
-	pub fn build<N: Into<String>>(table_name: N) -> UpdateBuilder<DB, false> {
+	pub fn build<N: Into<TableName>>(table_name: N) -> UpdateBuilder<DB, false, false> {
 		UpdateBuilder {
 			table_name: table_name.into(),
 			set_pairs: vec![],
 			where_clause_builder: None,
+			with_clause: None,
 		}
 	}
 }
@@ -28,13 +30,20 @@ impl<DB: Database> Clone for Update<DB> {
 			table_name: self.table_name.clone(),
 			set_pairs: self.set_pairs.clone(),
 			where_clause: self.where_clause.clone(),
+			with_clause: self.with_clause.clone(),
 		}
 	}
 }
 
 impl<DB: Database> From<Update<DB>> for Sql<DB> {
 	fn from(statement: Update<DB>) -> Self {
-		let mut sql: Sql<DB> = "update ".into_raw_sql();
+		let mut sql: Sql<DB> = "".into_raw_sql();
+
+		if let Some(with_clause) = statement.with_clause {
+			sql = sql.append(with_clause).raw_append(' ');
+		}
+
+		sql = sql.raw_append("update ");
 
 		sql = sql.append(TableReference::new(statement.table_name));
 
@@ -68,59 +77,113 @@ impl<DB: Database> From<Update<DB>> for Sql<DB> {
 	}
 }
 
-pub struct UpdateBuilder<DB: Database, const HAS_UPDATES: bool> {
-	table_name: String,
-	set_pairs: Vec<(ColRef, Option<Value<DB>>)>,
+pub struct UpdateBuilder<DB: Database, const HAS_UPDATES: bool, const HAS_WHERE: bool> {
+	table_name: TableName,
+	set_pairs: Vec<(ColRef, Sql<DB>)>,
 	where_clause_builder: Option<sql_lang::clause::sql_where::WhereBuilder<DB, true, false>>,
+	with_clause: Option<With<DB>>,
 }
 
-impl<DB: Database, const HAS_UPDATES: bool> UpdateBuilder<DB, HAS_UPDATES> {
+impl<DB: Database, const HAS_UPDATES: bool, const HAS_WHERE: bool>
+	UpdateBuilder<DB, HAS_UPDATES, HAS_WHERE>
+{
 	pub fn update_column<N: Into<String>, V: IntoSqlValue<DB>>(
 		mut self,
 		name: N,
 		value: V,
-	) -> UpdateBuilder<DB, true> {
+	) -> UpdateBuilder<DB, true, HAS_WHERE> {
 		self.set_pairs.push((
 			ColRef {
 				table_name: None,
 				column_name: name.into(),
 			},
-			value.into_sql_value(),
+			value.into_sql_value().into_sql(),
 		));
 
 		UpdateBuilder {
 			table_name: self.table_name,
 			set_pairs: self.set_pairs,
 			where_clause_builder: self.where_clause_builder,
+			with_clause: self.with_clause,
 		}
 	}
 
-	pub fn where_column_equal_to<N: Into<String>, V: IntoSqlValue<DB>>(
+	/// Prefixes the statement with common table expressions (i.e. `with ... update ...`).
+	pub fn with_cte_clause(mut self, clause: With<DB>) -> Self {
+		self.with_clause = Some(clause);
+		self
+	}
+
+	/// Sets the column to an arbitrary [Sql] expression, instead of a bound value
+	/// (e.g. `set "counter"=("counter" + 1)`).
+	pub fn update_column_expression<N: Into<String>, E: Into<Sql<DB>>>(
 		mut self,
 		name: N,
-		value: V,
-	) -> Self {
-		self.where_clause_builder = Some(if let Some(builder) = self.where_clause_builder {
-			builder.and_column_equal_to(name, value)
-		} else {
-			Where::build().column_equal_to(name, value)
-		});
+		expr: E,
+	) -> UpdateBuilder<DB, true, HAS_WHERE> {
+		self.set_pairs.push((
+			ColRef {
+				table_name: None,
+				column_name: name.into(),
+			},
+			expr.into(),
+		));
 
-		self
+		UpdateBuilder {
+			table_name: self.table_name,
+			set_pairs: self.set_pairs,
+			where_clause_builder: self.where_clause_builder,
+			with_clause: self.with_clause,
+		}
 	}
 
-	pub fn with_where_clause(mut self, clause: sql_lang::clause::Where<DB>) -> Self {
-		self.where_clause_builder = Some(if let Some(builder) = self.where_clause_builder {
-			builder.merge_with_clause(clause)
-		} else {
-			clause.into_builder()
-		});
+	pub fn where_column_equal_to<N: Into<String>, V: IntoSqlValue<DB>>(
+		self,
+		name: N,
+		value: V,
+	) -> UpdateBuilder<DB, HAS_UPDATES, true> {
+		UpdateBuilder {
+			table_name: self.table_name,
+			set_pairs: self.set_pairs,
+			where_clause_builder: Some(if let Some(builder) = self.where_clause_builder {
+				builder.and_column_equal_to(name, value)
+			} else {
+				Where::build().column_equal_to(name, value)
+			}),
+			with_clause: self.with_clause,
+		}
+	}
 
-		self
+	pub fn with_where_clause(
+		self,
+		clause: sql_lang::clause::Where<DB>,
+	) -> UpdateBuilder<DB, HAS_UPDATES, true> {
+		UpdateBuilder {
+			table_name: self.table_name,
+			set_pairs: self.set_pairs,
+			where_clause_builder: Some(if let Some(builder) = self.where_clause_builder {
+				builder.merge_with_clause(clause)
+			} else {
+				clause.into_builder()
+			}),
+			with_clause: self.with_clause,
+		}
+	}
+}
+
+impl<DB: Database, const HAS_UPDATES: bool> UpdateBuilder<DB, HAS_UPDATES, false> {
+	/// Explicitly updates every row in the table, in place of a WHERE clause.
+	pub fn all_rows(self) -> UpdateBuilder<DB, HAS_UPDATES, true> {
+		UpdateBuilder {
+			table_name: self.table_name,
+			set_pairs: self.set_pairs,
+			where_clause_builder: self.where_clause_builder,
+			with_clause: self.with_clause,
+		}
 	}
 }
 
-impl<DB: Database> UpdateBuilder<DB, true> {
+impl<DB: Database> UpdateBuilder<DB, true, true> {
 	pub fn finalize(self) -> Result<Update<DB>, SyntaxError> {
 		Ok(Update {
 			table_name: self.table_name,
@@ -129,6 +192,7 @@ impl<DB: Database> UpdateBuilder<DB, true> {
 				.where_clause_builder
 				.map(|w| w.finalize())
 				.transpose()?,
+			with_clause: self.with_clause,
 		})
 	}
 
@@ -36,3 +36,11 @@ fn mysql() -> Result<(), SyntaxError> {
 
 	test::<DB>("group by `foo`, `bar`, coalesce(a, b)", &[])
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(r#"group by "foo", "bar", coalesce(a, b)"#, &[])
+}
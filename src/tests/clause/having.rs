@@ -33,3 +33,11 @@ fn mysql() -> Result<(), SyntaxError> {
 
 	test::<DB>("having `foo`=?", &[1i32.into_sql_value()])
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(r#"having "foo"=?"#, &[1i32.into_sql_value()])
+}
@@ -3,3 +3,4 @@ mod having;
 mod order_by;
 mod sql_from;
 mod sql_where;
+mod with;
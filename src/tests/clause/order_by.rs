@@ -1,21 +1,19 @@
 use crate::sql_lang::IntoRawSql;
 use crate::tests::compare_sql;
 use crate::value::Value;
-use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+use crate::{sql_lang, Database, IntoSql, Sql, SyntaxError};
 
 fn test<DB: Database>(
 	target_text: &str,
 	target_params: &[Option<Value<DB>>],
 ) -> Result<(), SyntaxError>
 where
-	i32: IntoSqlValue<DB>,
 	Sql<DB>: From<sql_lang::clause::OrderBy<DB>>,
 {
 	let sql: Sql<DB> = sql_lang::clause::OrderBy::build()
 		.order_by_column_asc("foo")
 		.order_by_column_desc("bar")
 		.order_by_expression("coalesce(a, b) desc".into_raw_sql())
-		.limit(10, 200)
 		.finalize()?
 		.into_sql();
 
@@ -28,8 +26,8 @@ fn postgres() -> Result<(), SyntaxError> {
 	type DB = sqlx::Postgres;
 
 	test::<DB>(
-		r#"order by "foo" asc, "bar" desc, coalesce(a, b) desc limit $1 offset $2"#,
-		&[10u32.into_sql_value(), 200u32.into_sql_value()],
+		r#"order by "foo" asc, "bar" desc, coalesce(a, b) desc"#,
+		&[],
 	)
 }
 
@@ -38,8 +36,16 @@ fn postgres() -> Result<(), SyntaxError> {
 fn mysql() -> Result<(), SyntaxError> {
 	type DB = sqlx::MySql;
 
+	test::<DB>("order by `foo` asc, `bar` desc, coalesce(a, b) desc", &[])
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
 	test::<DB>(
-		"order by `foo` asc, `bar` desc, coalesce(a, b) desc limit ? offset ?",
-		&[10u32.into_sql_value(), 200u32.into_sql_value()],
+		r#"order by "foo" asc, "bar" desc, coalesce(a, b) desc"#,
+		&[],
 	)
 }
@@ -52,3 +52,14 @@ fn mysql() -> Result<(), SyntaxError> {
 		&[],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"from "some_table" "st" inner join "another_table" "at" on "st"."fk_id" = "at"."id""#,
+		&[],
+	)
+}
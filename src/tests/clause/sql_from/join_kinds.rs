@@ -0,0 +1,88 @@
+#[cfg(feature = "mysql")]
+use crate::error::SyntaxErrorKind;
+use crate::tests::compare_sql;
+#[cfg(feature = "mysql")]
+use crate::tests::expect_syntax_error;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::clause::SqlFrom<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::clause::SqlFrom::build("orders", "o")
+		.join_using("order_lines", "ol", ["order_id"])
+		.left_join_using("shipments", "s", ["order_id", "warehouse_id"])
+		.cross_join("currencies", "c")
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_full_outer<DB: Database>(target_text: &str) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::clause::SqlFrom<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::clause::SqlFrom::build("budget", "b")
+		.full_outer_join(
+			"actual",
+			"a",
+			sql_lang::clause::SqlOn::build()
+				.fk("b", "account_id", "a", "account_id")
+				.finalize()?,
+		)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, &[])
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"from "orders" "o" inner join "order_lines" "ol" using ("order_id") left join "shipments" "s" using ("order_id", "warehouse_id") cross join "currencies" "c""#,
+		&[],
+	)?;
+
+	test_full_outer::<DB>(
+		r#"from "budget" "b" full outer join "actual" "a" on "b"."account_id" = "a"."account_id""#,
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"from `orders` `o` inner join `order_lines` `ol` using (`order_id`) left join `shipments` `s` using (`order_id`, `warehouse_id`) cross join `currencies` `c`",
+		&[],
+	)?;
+
+	expect_syntax_error(
+		test_full_outer::<DB>(""),
+		SyntaxErrorKind::UnsupportedFeature,
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"from "orders" "o" inner join "order_lines" "ol" using ("order_id") left join "shipments" "s" using ("order_id", "warehouse_id") cross join "currencies" "c""#,
+		&[],
+	)?;
+
+	test_full_outer::<DB>(
+		r#"from "budget" "b" full outer join "actual" "a" on "b"."account_id" = "a"."account_id""#,
+	)
+}
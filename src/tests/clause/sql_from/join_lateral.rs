@@ -0,0 +1,76 @@
+#[cfg(feature = "sqlite")]
+use crate::error::SyntaxErrorKind;
+use crate::tests::compare_sql;
+#[cfg(feature = "sqlite")]
+use crate::tests::expect_syntax_error;
+use crate::value::Value;
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use crate::IntoSqlValue;
+use crate::{sql_lang, Database, IntoSql, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::clause::SqlFrom<DB>>,
+{
+	let latest_orders = |alias: &str| {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build_with_join(
+			sql_lang::clause::SqlFrom::build("orders", alias).finalize()?,
+		)
+		.select_column(alias, "total")
+		.with_where_clause(
+			sql_lang::clause::Where::build_with_join()
+				.expression(
+					TableAndColumnReference::new(alias, "customer_id")
+						.equal_to(TableAndColumnReference::new("c", "id")),
+				)
+				.finalize()?,
+		)
+		.order_by([(alias, "created", false)])
+		.limit(3)
+		.finalize()
+	};
+
+	let sql: Sql<DB> = sql_lang::clause::SqlFrom::build("customers", "c")
+		.left_join_lateral(latest_orders("o")?, "lo")
+		.cross_join_lateral(latest_orders("o2")?, "lo2")
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"from "customers" "c" left join lateral (select "o"."total" from "orders" "o" where "o"."customer_id" = "c"."id" order by "o"."created" desc limit $1) "lo" on true cross join lateral (select "o2"."total" from "orders" "o2" where "o2"."customer_id" = "c"."id" order by "o2"."created" desc limit $2) "lo2""#,
+		&[3u32.into_sql_value(), 3u32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"from `customers` `c` left join lateral (select `o`.`total` from `orders` `o` where `o`.`customer_id` = `c`.`id` order by `o`.`created` desc limit ?) `lo` on true cross join lateral (select `o2`.`total` from `orders` `o2` where `o2`.`customer_id` = `c`.`id` order by `o2`.`created` desc limit ?) `lo2`",
+		&[3u32.into_sql_value(), 3u32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		test::<sqlx::Sqlite>("", &[]),
+		SyntaxErrorKind::UnsupportedFeature,
+	)
+}
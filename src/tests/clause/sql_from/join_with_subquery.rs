@@ -55,3 +55,14 @@ fn mysql() -> Result<(), SyntaxError> {
 		&[1i32.into_sql_value()],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"from "some_table" "st" inner join (select "id" from "another_table" where "id"=?) "at" on "st"."fk_id" = "at"."id""#,
+		&[1i32.into_sql_value()],
+	)
+}
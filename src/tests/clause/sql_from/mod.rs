@@ -1,3 +1,5 @@
 mod join;
+mod join_kinds;
+mod join_lateral;
 mod join_with_subquery;
 mod single_table;
@@ -32,3 +32,11 @@ fn mysql() -> Result<(), SyntaxError> {
 
 	test::<DB>("from `some_table` `st`", &[])
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(r#"from "some_table" "st""#, &[])
+}
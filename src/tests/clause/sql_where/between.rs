@@ -0,0 +1,86 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::clause::Where<DB>>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::clause::Where::build()
+			.column_between("age", 18i32, 65i32)
+			.and_column_in_range("created", "2024-01-01".."2024-02-01")
+			.or_column_in_range("score", 1i32..=10i32)
+			.and_expression(ColumnReference::new("rank").not_between(3i32, 5i32))
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"where "age" between $1 and $2 and ("created" >= $3 and "created" < $4) or "score" between $5 and $6 and "rank" not between $7 and $8"#,
+		&[
+			18i32.into_sql_value(),
+			65i32.into_sql_value(),
+			"2024-01-01".into_sql_value(),
+			"2024-02-01".into_sql_value(),
+			1i32.into_sql_value(),
+			10i32.into_sql_value(),
+			3i32.into_sql_value(),
+			5i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"where `age` between ? and ? and (`created` >= ? and `created` < ?) or `score` between ? and ? and `rank` not between ? and ?",
+		&[
+			18i32.into_sql_value(),
+			65i32.into_sql_value(),
+			"2024-01-01".into_sql_value(),
+			"2024-02-01".into_sql_value(),
+			1i32.into_sql_value(),
+			10i32.into_sql_value(),
+			3i32.into_sql_value(),
+			5i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"where "age" between ? and ? and ("created" >= ? and "created" < ?) or "score" between ? and ? and "rank" not between ? and ?"#,
+		&[
+			18i32.into_sql_value(),
+			65i32.into_sql_value(),
+			"2024-01-01".into_sql_value(),
+			"2024-02-01".into_sql_value(),
+			1i32.into_sql_value(),
+			10i32.into_sql_value(),
+			3i32.into_sql_value(),
+			5i32.into_sql_value(),
+		],
+	)
+}
@@ -49,3 +49,18 @@ fn mysql() -> Result<(), SyntaxError> {
 		],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"where "one"=? and "two"=? and "three"=?"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)
+}
@@ -0,0 +1,74 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::clause::Where<DB>>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		let subquery = |table_name: &str, alias: &str| {
+			sql_lang::statement::Select::build_with_join(
+				sql_lang::clause::SqlFrom::build(table_name, alias).finalize()?,
+			)
+			.select_column(alias, "id")
+			.with_where_clause(
+				sql_lang::clause::Where::build_with_join()
+					.expression(
+						TableAndColumnReference::new(alias, "customer_id")
+							.equal_to(TableAndColumnReference::new("c", "id")),
+					)
+					.finalize()?,
+			)
+			.finalize()
+		};
+
+		sql_lang::clause::Where::build_with_join()
+			.column_equal_to("c", "active", 1i32)
+			.and_exists(subquery("orders", "o")?)
+			.or_not_exists(subquery("refunds", "r")?)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"where "c"."active"=$1 and exists (select "o"."id" from "orders" "o" where "o"."customer_id" = "c"."id") or not exists (select "r"."id" from "refunds" "r" where "r"."customer_id" = "c"."id")"#,
+		&[1i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"where `c`.`active`=? and exists (select `o`.`id` from `orders` `o` where `o`.`customer_id` = `c`.`id`) or not exists (select `r`.`id` from `refunds` `r` where `r`.`customer_id` = `c`.`id`)",
+		&[1i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"where "c"."active"=? and exists (select "o"."id" from "orders" "o" where "o"."customer_id" = "c"."id") or not exists (select "r"."id" from "refunds" "r" where "r"."customer_id" = "c"."id")"#,
+		&[1i32.into_sql_value()],
+	)
+}
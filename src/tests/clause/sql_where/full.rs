@@ -74,3 +74,26 @@ fn mysql() -> Result<(), SyntaxError> {
 		],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"where "one"=? and "two"=? and "three"=? and "foo" in (?,?,?) and "bar" in (?,?,?) and "four"=? or ("five"=?)"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+			4i32.into_sql_value(),
+			5i32.into_sql_value(),
+			6i32.into_sql_value(),
+			7i32.into_sql_value(),
+			8i32.into_sql_value(),
+			9i32.into_sql_value(),
+			"10".into_sql_value(),
+			11i32.into_sql_value(),
+		],
+	)
+}
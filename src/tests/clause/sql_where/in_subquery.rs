@@ -38,3 +38,11 @@ fn mysql() -> Result<(), SyntaxError> {
 
 	test::<DB>("where `bar_id` in (select `id` from `bar`)", &[])
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(r#"where "bar_id" in (select "id" from "bar")"#, &[])
+}
@@ -0,0 +1,69 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::clause::Where<DB>>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::clause::Where::build()
+			.column_like("name", pattern::contains("50%_off"))
+			.or_expression(ColumnReference::new("title").ilike(pattern::starts_with("Sale")))
+			.and_expression(ColumnReference::new("code").not_like("X\\_%"))
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"where "name" like $1 or "title" ilike $2 and "code" not like $3"#,
+		&[
+			"%50\\%\\_off%".into_sql_value(),
+			"Sale%".into_sql_value(),
+			"X\\_%".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"where `name` like ? or lower(`title`) like lower(?) and `code` not like ?",
+		&[
+			"%50\\%\\_off%".into_sql_value(),
+			"Sale%".into_sql_value(),
+			"X\\_%".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"where "name" like ? escape '\' or lower("title") like lower(?) escape '\' and "code" not like ? escape '\'"#,
+		&[
+			"%50\\%\\_off%".into_sql_value(),
+			"Sale%".into_sql_value(),
+			"X\\_%".into_sql_value(),
+		],
+	)
+}
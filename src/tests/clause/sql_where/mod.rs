@@ -1,5 +1,8 @@
+mod between;
 mod column;
+mod exists;
 mod expression;
 mod full;
 mod in_subquery;
 mod in_values;
+mod like;
@@ -0,0 +1,82 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Select<DB>>,
+{
+	let tree = sql_lang::statement::Compound::build(
+		sql_lang::statement::Select::build("node")
+			.select_column("id")
+			.where_column_equal_to("id", 1i32)
+			.finalize()?,
+	)
+	.union_all(
+		sql_lang::statement::Select::build_with_join(
+			sql_lang::clause::SqlFrom::build("node", "n")
+				.inner_join(
+					"tree",
+					"t",
+					sql_lang::clause::SqlOn::build()
+						.fk("n", "parent_id", "t", "id")
+						.finalize()?,
+				)
+				.finalize()?,
+		)
+		.select_column("n", "id")
+		.finalize()?,
+	)
+	.finalize()?;
+
+	let sql: Sql<DB> = sql_lang::statement::Select::build("tree")
+		.with_cte_clause(
+			sql_lang::clause::With::build()
+				.recursive()
+				.table_with_columns("tree", ["id"], tree)
+				.finalize()?,
+		)
+		.select_column("id")
+		.where_column_equal_to("id", 3i32)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"with recursive "tree" ("id") as (select "id" from "node" where "id"=$1 union all select "n"."id" from "node" "n" inner join "tree" "t" on "n"."parent_id" = "t"."id") select "id" from "tree" where "id"=$2"#,
+		&[1i32.into_sql_value(), 3i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"with recursive `tree` (`id`) as (select `id` from `node` where `id`=? union all select `n`.`id` from `node` `n` inner join `tree` `t` on `n`.`parent_id` = `t`.`id`) select `id` from `tree` where `id`=?",
+		&[1i32.into_sql_value(), 3i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"with recursive "tree" ("id") as (select "id" from "node" where "id"=? union all select "n"."id" from "node" "n" inner join "tree" "t" on "n"."parent_id" = "t"."id") select "id" from "tree" where "id"=?"#,
+		&[1i32.into_sql_value(), 3i32.into_sql_value()],
+	)
+}
@@ -0,0 +1,41 @@
+use crate::crud::ExpectedRowCount;
+use crate::error::CrudErrorKind;
+
+#[test]
+fn contains() {
+	assert!(ExpectedRowCount::Exactly(1).contains(1));
+	assert!(!ExpectedRowCount::Exactly(1).contains(0));
+	assert!(!ExpectedRowCount::Exactly(1).contains(2));
+
+	assert!(ExpectedRowCount::AtMost(2).contains(0));
+	assert!(ExpectedRowCount::AtMost(2).contains(2));
+	assert!(!ExpectedRowCount::AtMost(2).contains(3));
+
+	assert!(!ExpectedRowCount::Between(2, 4).contains(1));
+	assert!(ExpectedRowCount::Between(2, 4).contains(2));
+	assert!(ExpectedRowCount::Between(2, 4).contains(4));
+	assert!(!ExpectedRowCount::Between(2, 4).contains(5));
+}
+
+#[test]
+fn display() {
+	assert_eq!(ExpectedRowCount::Exactly(1).to_string(), "exactly 1");
+	assert_eq!(ExpectedRowCount::AtMost(2).to_string(), "at most 2");
+	assert_eq!(
+		ExpectedRowCount::Between(2, 4).to_string(),
+		"between 2 and 4"
+	);
+}
+
+#[test]
+fn validate() {
+	assert!(ExpectedRowCount::Exactly(0).validate().is_ok());
+	assert!(ExpectedRowCount::Between(3, 3).validate().is_ok());
+
+	let error = ExpectedRowCount::Between(4, 2).validate().unwrap_err();
+
+	assert!(matches!(
+		error.kind(),
+		CrudErrorKind::InvalidExpectedRowCount(ExpectedRowCount::Between(4, 2))
+	));
+}
@@ -0,0 +1,60 @@
+use crate::crud::insert::InsertBuilder;
+use crate::tests::compare_sql;
+use crate::{crud, Database, IntoSqlValue, SyntaxError};
+
+fn build<DB: Database>() -> InsertBuilder<DB> {
+	crud::insert_row("users")
+		.column("name", "alice")
+		.column("email", "alice@example.com")
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	use crate::vendor::postgres::crud::insert::sql_returning_id;
+
+	type DB = sqlx::Postgres;
+
+	compare_sql(
+		&sql_returning_id(build::<DB>(), "id".to_string())?,
+		r#"insert into "users" ("name","email") values ($1,$2) returning cast("id" as bigint)"#,
+		&[
+			"alice".into_sql_value(),
+			"alice@example.com".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres_row() -> Result<(), SyntaxError> {
+	use crate::vendor::postgres::crud::insert::sql_returning_row;
+
+	type DB = sqlx::Postgres;
+
+	compare_sql(
+		&sql_returning_row(build::<DB>())?,
+		r#"insert into "users" ("name","email") values ($1,$2) returning *"#,
+		&[
+			"alice".into_sql_value(),
+			"alice@example.com".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	use crate::vendor::sqlite::crud::insert::sql_returning_id;
+
+	type DB = sqlx::Sqlite;
+
+	compare_sql(
+		&sql_returning_id(build::<DB>(), "id".to_string())?,
+		r#"insert into "users" ("name","email") values (?,?) returning "id""#,
+		&[
+			"alice".into_sql_value(),
+			"alice@example.com".into_sql_value(),
+		],
+	)
+}
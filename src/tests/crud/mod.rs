@@ -0,0 +1,7 @@
+mod expected_row_count;
+// mysql has no returning clause
+#[cfg(any(feature = "postgres", feature = "sqlite"))]
+mod insert_returning;
+mod native_upsert;
+mod outcome;
+mod replace;
@@ -0,0 +1,234 @@
+use crate::crud::replace::Upsert;
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{crud, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<Upsert<DB>>,
+{
+	let sql: Sql<DB> = crud::replace_row("counters")
+		.key_columns(["id", "region"])
+		.insert_column("id", 7i32)
+		.insert_column("region", "eu")
+		.replace_column("label", "busy")
+		.insert_column("created_by", "import")
+		.update_column("updated_by", "sync")
+		.native_upsert()
+		.into_upsert()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_without_updates<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<Upsert<DB>>,
+{
+	let sql: Sql<DB> = crud::replace_row("counters")
+		.key_columns(["id"])
+		.insert_column("id", 7i32)
+		.insert_column("label", "busy")
+		.native_upsert()
+		.into_upsert()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_expression<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<Upsert<DB>>,
+{
+	use crate::sql_lang::expression::TableAndColumnReference;
+
+	let sql: Sql<DB> = crud::replace_row("counters")
+		.key_columns(["id"])
+		.insert_column("id", 7i32)
+		.insert_column("hits", 1i32)
+		.update_column_expression(
+			"hits",
+			TableAndColumnReference::new("counters", "hits") + 1i32,
+		)
+		.native_upsert()
+		.into_upsert()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"insert into "counters" ("id","region","label","created_by") values ($1,$2,$3,$4) on conflict ("id","region") do update set "label"=$5,"updated_by"=$6"#,
+		&[
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+			"busy".into_sql_value(),
+			"import".into_sql_value(),
+			"busy".into_sql_value(),
+			"sync".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres_without_updates() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test_without_updates::<DB>(
+		r#"insert into "counters" ("id","label") values ($1,$2) on conflict ("id") do nothing"#,
+		&[7i32.into_sql_value(), "busy".into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres_expression() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test_expression::<DB>(
+		r#"insert into "counters" ("id","hits") values ($1,$2) on conflict ("id") do update set "hits"=("counters"."hits" + $3)"#,
+		&[
+			7i32.into_sql_value(),
+			1i32.into_sql_value(),
+			1i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"insert into `counters` (`id`,`region`,`label`,`created_by`) values (?,?,?,?) on duplicate key update `label`=?,`updated_by`=?",
+		&[
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+			"busy".into_sql_value(),
+			"import".into_sql_value(),
+			"busy".into_sql_value(),
+			"sync".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql_without_updates() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test_without_updates::<DB>(
+		"insert into `counters` (`id`,`label`) values (?,?) on duplicate key update `id`=`id`",
+		&[7i32.into_sql_value(), "busy".into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql_expression() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test_expression::<DB>(
+		"insert into `counters` (`id`,`hits`) values (?,?) on duplicate key update `hits`=(`counters`.`hits` + ?)",
+		&[
+			7i32.into_sql_value(),
+			1i32.into_sql_value(),
+			1i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"insert into "counters" ("id","region","label","created_by") values (?,?,?,?) on conflict ("id","region") do update set "label"=?,"updated_by"=?"#,
+		&[
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+			"busy".into_sql_value(),
+			"import".into_sql_value(),
+			"busy".into_sql_value(),
+			"sync".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite_without_updates() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test_without_updates::<DB>(
+		r#"insert into "counters" ("id","label") values (?,?) on conflict ("id") do nothing"#,
+		&[7i32.into_sql_value(), "busy".into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite_expression() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test_expression::<DB>(
+		r#"insert into "counters" ("id","hits") values (?,?) on conflict ("id") do update set "hits"=("counters"."hits" + ?)"#,
+		&[
+			7i32.into_sql_value(),
+			1i32.into_sql_value(),
+			1i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite_split_update() -> Result<(), SyntaxError> {
+	use crate::error::SyntaxErrorKind;
+
+	type DB = sqlx::Sqlite;
+
+	let builder = crud::replace_row::<DB, _>("counters")
+		.key_columns(["id"])
+		.insert_column("id", 7i32)
+		.replace_column("label", "busy")
+		.native_upsert();
+
+	let where_clause = builder.key_where_clause()?;
+	let mut upsert = builder.into_upsert()?;
+	let update_statement = upsert
+		.split_update(where_clause)?
+		.ok_or_else(|| SyntaxError::new(SyntaxErrorKind::Other, "nothing to update".to_string()))?;
+
+	compare_sql(
+		&upsert.into_sql(),
+		r#"insert into "counters" ("id","label") values (?,?) on conflict ("id") do nothing"#,
+		&[7i32.into_sql_value(), "busy".into_sql_value()],
+	)?;
+
+	compare_sql(
+		&update_statement.into_sql(),
+		r#"update "counters" set "label"=? where "id"=?"#,
+		&["busy".into_sql_value(), 7i32.into_sql_value()],
+	)
+}
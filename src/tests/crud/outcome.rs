@@ -0,0 +1,41 @@
+use crate::crud::{CrudOutcome, ReplaceAction};
+
+fn assert_outcome(outcome: CrudOutcome, rows_affected: u64, action: Option<ReplaceAction>) {
+	assert_eq!(outcome.rows_affected(), rows_affected);
+	assert_eq!(outcome.replace_action(), action);
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() {
+	use crate::vendor::postgres::crud::replace::upsert_outcome;
+
+	assert_outcome(upsert_outcome(Some(true)), 1, Some(ReplaceAction::Inserted));
+	assert_outcome(upsert_outcome(Some(false)), 1, Some(ReplaceAction::Updated));
+	assert_outcome(upsert_outcome(None), 0, Some(ReplaceAction::Unchanged));
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() {
+	use crate::vendor::mysql::crud::replace::upsert_outcome;
+
+	assert_outcome(upsert_outcome(0), 0, Some(ReplaceAction::Unchanged));
+	assert_outcome(upsert_outcome(1), 1, None);
+	assert_outcome(upsert_outcome(2), 1, Some(ReplaceAction::Updated));
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() {
+	use crate::vendor::sqlite::crud::replace::upsert_outcome;
+
+	assert_outcome(upsert_outcome(1, 0), 1, Some(ReplaceAction::Inserted));
+	assert_outcome(upsert_outcome(0, 1), 1, Some(ReplaceAction::Updated));
+	assert_outcome(upsert_outcome(0, 0), 0, Some(ReplaceAction::Unchanged));
+}
+
+#[test]
+fn other_builders() {
+	assert_outcome(CrudOutcome::new(3), 3, None);
+}
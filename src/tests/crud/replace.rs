@@ -0,0 +1,148 @@
+use crate::crud::replace::ReplaceBuilder;
+use crate::error::SyntaxErrorKind;
+use crate::sql_lang::statement::{Select, Update};
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{crud, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn build<DB: Database>() -> ReplaceBuilder<DB, true, true>
+where
+	i32: IntoSqlValue<DB>,
+{
+	crud::replace_row("counters")
+		.key_columns(["id", "region"])
+		.insert_column("id", 7i32)
+		.insert_column("region", "eu")
+		.replace_column("label", "busy")
+		.insert_column("created_by", "import")
+		.update_column("updated_by", "sync")
+}
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<Select<DB>>,
+{
+	let builder = build::<DB>();
+
+	let sql: Sql<DB> = builder
+		.existing_rows_select(builder.key_where_clause()?)?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_update<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<Update<DB>>,
+{
+	let builder = build::<DB>();
+	let where_clause = builder.key_where_clause()?;
+
+	let sql: Sql<DB> = builder
+		.into_update(where_clause)?
+		.ok_or_else(|| SyntaxError::new(SyntaxErrorKind::Other, "nothing to update".to_string()))?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "id", "region" from "counters" where "id"=$1 and "region"=$2 limit $3 for update"#,
+		&[
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+			2u32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres_update() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test_update::<DB>(
+		r#"update "counters" set "label"=$1,"updated_by"=$2 where "id"=$3 and "region"=$4"#,
+		&[
+			"busy".into_sql_value(),
+			"sync".into_sql_value(),
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select `id`, `region` from `counters` where `id`=? and `region`=? limit ? for update",
+		&[
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+			2u32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql_update() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test_update::<DB>(
+		"update `counters` set `label`=?,`updated_by`=? where `id`=? and `region`=?",
+		&[
+			"busy".into_sql_value(),
+			"sync".into_sql_value(),
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "id", "region" from "counters" where "id"=? and "region"=? limit ?"#,
+		&[
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+			2u32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite_update() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test_update::<DB>(
+		r#"update "counters" set "label"=?,"updated_by"=? where "id"=? and "region"=?"#,
+		&[
+			"busy".into_sql_value(),
+			"sync".into_sql_value(),
+			7i32.into_sql_value(),
+			"eu".into_sql_value(),
+		],
+	)
+}
@@ -0,0 +1,69 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+		// the std::ops traits must not make the SqlExpression methods ambiguous
+		use std::ops::Neg;
+
+		(ColumnReference::new("price") * ColumnReference::new("qty") + 5i32)
+			.times(ColumnReference::new("tax_rate").minus(1i32).neg())
+			.greater_than(-(TableAndColumnReference::new("o", "total") % 7i32))
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"((("price" * "qty") + $1) * -(("tax_rate" - $2))) > -(("o"."total" % $3))"#,
+		&[
+			5i32.into_sql_value(),
+			1i32.into_sql_value(),
+			7i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"(((`price` * `qty`) + ?) * -((`tax_rate` - ?))) > -((`o`.`total` % ?))",
+		&[
+			5i32.into_sql_value(),
+			1i32.into_sql_value(),
+			7i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"((("price" * "qty") + ?) * -(("tax_rate" - ?))) > -(("o"."total" % ?))"#,
+		&[
+			5i32.into_sql_value(),
+			1i32.into_sql_value(),
+			7i32.into_sql_value(),
+		],
+	)
+}
@@ -0,0 +1,92 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build("orders")
+			.select_expression(
+				count(case_when(
+					ColumnReference::new("status").equal_to("shipped"),
+					1i32,
+				)),
+				"shipped_count",
+			)
+			.select_expression(
+				case(ColumnReference::new("priority"))
+					.when(1i32, "high")
+					.when(2i32, "normal")
+					.else_("low"),
+				"priority_label",
+			)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select count(case when "status" = $1 then $2 end) as "shipped_count", case "priority" when $3 then $4 when $5 then $6 else $7 end as "priority_label" from "orders""#,
+		&[
+			"shipped".into_sql_value(),
+			1i32.into_sql_value(),
+			1i32.into_sql_value(),
+			"high".into_sql_value(),
+			2i32.into_sql_value(),
+			"normal".into_sql_value(),
+			"low".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select count(case when `status` = ? then ? end) as `shipped_count`, case `priority` when ? then ? when ? then ? else ? end as `priority_label` from `orders`",
+		&[
+			"shipped".into_sql_value(),
+			1i32.into_sql_value(),
+			1i32.into_sql_value(),
+			"high".into_sql_value(),
+			2i32.into_sql_value(),
+			"normal".into_sql_value(),
+			"low".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select count(case when "status" = ? then ? end) as "shipped_count", case "priority" when ? then ? when ? then ? else ? end as "priority_label" from "orders""#,
+		&[
+			"shipped".into_sql_value(),
+			1i32.into_sql_value(),
+			1i32.into_sql_value(),
+			"high".into_sql_value(),
+			2i32.into_sql_value(),
+			"normal".into_sql_value(),
+			"low".into_sql_value(),
+		],
+	)
+}
@@ -0,0 +1,59 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError> {
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		day_diff(ColumnReference::new("start_date"), "2000-01-01")
+			.greater_than(minute_diff(
+				ColumnReference::new("start_date"),
+				"2000-01-01",
+			))
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"date_part('day',$1::timestamp - "start_date"::timestamp) > (date_part('day',$2::timestamp - "start_date"::timestamp) * 24 * 60 + date_part('hour',$3::timestamp - "start_date"::timestamp) * 60 + date_part('minute',$4::timestamp - "start_date"::timestamp))"#,
+		&[
+			"2000-01-01".into_sql_value(),
+			"2000-01-01".into_sql_value(),
+			"2000-01-01".into_sql_value(),
+			"2000-01-01".into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"timestampdiff(DAY,`start_date`,?) > timestampdiff(MINUTE,`start_date`,?)",
+		&["2000-01-01".into_sql_value(), "2000-01-01".into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"((cast(strftime('%s',?) as integer) - cast(strftime('%s',"start_date") as integer)) / 86400) > ((cast(strftime('%s',?) as integer) - cast(strftime('%s',"start_date") as integer)) / 60)"#,
+		&["2000-01-01".into_sql_value(), "2000-01-01".into_sql_value()],
+	)
+}
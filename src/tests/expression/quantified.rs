@@ -0,0 +1,100 @@
+#[cfg(feature = "sqlite")]
+use crate::error::SyntaxErrorKind;
+use crate::tests::compare_sql;
+#[cfg(feature = "sqlite")]
+use crate::tests::expect_syntax_error;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build("products")
+			.select_column("id")
+			.with_where_clause(
+				sql_lang::clause::Where::build()
+					.expression(
+						ColumnReference::new("price").greater_than(all(
+							sql_lang::statement::Select::build("competitor_products")
+								.select_column("price")
+								.finalize()?,
+						)?),
+					)
+					.or_expression(
+						ColumnReference::new("category_id").equal_to(any(
+							sql_lang::statement::Select::build("categories")
+								.select_column("id")
+								.where_column_equal_to("featured", 1i32)
+								.finalize()?,
+						)?),
+					)
+					.finalize()?,
+			)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "id" from "products" where "price" > all (select "price" from "competitor_products") or "category_id" = any (select "id" from "categories" where "featured"=$1)"#,
+		&[1i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres_array() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	compare_sql::<DB>(
+		&{
+			use sql_lang::expression::*;
+
+			ColumnReference::new("id")
+				.equal_to(any_array(vec![1i32, 2, 3]))
+				.and(
+					ColumnReference::new("status").not_equal_to(all_array(&["closed", "void"][..])),
+				)
+				.into_sql()
+		},
+		r#""id" = any($1) and "status" <> all($2)"#,
+		&[
+			crate::value::IntoSqlArray::into_sql_array(vec![1i32, 2, 3]),
+			crate::value::IntoSqlArray::into_sql_array(vec!["closed", "void"]),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select `id` from `products` where `price` > all (select `price` from `competitor_products`) or `category_id` = any (select `id` from `categories` where `featured`=?)",
+		&[1i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		test::<sqlx::Sqlite>("", &[]),
+		SyntaxErrorKind::UnsupportedFeature,
+	)
+}
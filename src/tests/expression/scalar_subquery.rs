@@ -0,0 +1,76 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build("products")
+			.select_column("id")
+			.select_expression(
+				ColumnReference::new("price")
+					- ScalarSubquery::new(
+						sql_lang::statement::Select::build("products")
+							.select_expression(min(ColumnReference::new("price")), "min_price")
+							.finalize()?,
+					),
+				"premium",
+			)
+			.with_where_clause(
+				sql_lang::clause::Where::build()
+					.expression(
+						ColumnReference::new("stock").greater_than(ScalarSubquery::new(
+							sql_lang::statement::Select::build("settings")
+								.select_column("min_stock")
+								.where_column_equal_to("id", 1i32)
+								.finalize()?,
+						)),
+					)
+					.finalize()?,
+			)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "id", ("price" - (select min("price") as "min_price" from "products")) as "premium" from "products" where "stock" > (select "min_stock" from "settings" where "id"=$1)"#,
+		&[1i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select `id`, (`price` - (select min(`price`) as `min_price` from `products`)) as `premium` from `products` where `stock` > (select `min_stock` from `settings` where `id`=?)",
+		&[1i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "id", ("price" - (select min("price") as "min_price" from "products")) as "premium" from "products" where "stock" > (select "min_stock" from "settings" where "id"=?)"#,
+		&[1i32.into_sql_value()],
+	)
+}
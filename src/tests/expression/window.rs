@@ -0,0 +1,79 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::function::ast::FrameBound;
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build("scores")
+			.select_column("player")
+			.select_expression(
+				row_number().over(
+					window()
+						.partition_by_column("game")
+						.order_by(sql_lang::clause::OrderBy::try_from(vec![("score", false)])?),
+				),
+				"position",
+			)
+			.select_expression(
+				lag(ColumnReference::new("score"), 1).over(
+					window().order_by(sql_lang::clause::OrderBy::try_from(vec![("day", true)])?),
+				),
+				"previous_score",
+			)
+			.select_expression(
+				max(ColumnReference::new("score")).over(
+					window()
+						.partition_by_column("player")
+						.rows_between(FrameBound::Preceding(6), FrameBound::CurrentRow),
+				),
+				"best_score",
+			)
+			.where_column_equal_to("season", 3i32)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "player", row_number() over (partition by "game" order by "score" desc) as "position", lag("score", 1) over (order by "day" asc) as "previous_score", max("score") over (partition by "player" rows between 6 preceding and current row) as "best_score" from "scores" where "season"=$1"#,
+		&[3i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select `player`, row_number() over (partition by `game` order by `score` desc) as `position`, lag(`score`, 1) over (order by `day` asc) as `previous_score`, max(`score`) over (partition by `player` rows between 6 preceding and current row) as `best_score` from `scores` where `season`=?",
+		&[3i32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "player", row_number() over (partition by "game" order by "score" desc) as "position", lag("score", 1) over (order by "day" asc) as "previous_score", max("score") over (partition by "player" rows between 6 preceding and current row) as "best_score" from "scores" where "season"=?"#,
+		&[3i32.into_sql_value()],
+	)
+}
@@ -3,6 +3,7 @@ use crate::value::Value;
 use crate::{Database, Sql, SyntaxError};
 
 mod clause;
+mod crud;
 mod expression;
 mod statement;
 
@@ -34,3 +35,20 @@ where
 
 	return Ok(());
 }
+
+/// Checks that the result is a [SyntaxError] of the specified kind.
+pub fn expect_syntax_error<T>(
+	result: Result<T, SyntaxError>,
+	kind: SyntaxErrorKind,
+) -> Result<(), SyntaxError> {
+	match result {
+		Err(error) if std::mem::discriminant(error.kind()) == std::mem::discriminant(&kind) => {
+			Ok(())
+		}
+		Err(error) => Err(error),
+		Ok(_) => Err(SyntaxError::new(
+			SyntaxErrorKind::Other,
+			format!("expected a syntax error: {}", kind),
+		)),
+	}
+}
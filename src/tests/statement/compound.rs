@@ -0,0 +1,380 @@
+use crate::error::SyntaxErrorKind;
+use crate::sql_lang::clause::SqlOn;
+use crate::tests::{compare_sql, expect_syntax_error};
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn member<DB: Database>(
+	table_name: &str,
+	author: i32,
+) -> Result<sql_lang::statement::Select<DB>, SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	sql_lang::statement::Select::build(table_name)
+		.select_column("id")
+		.where_column_equal_to("author", author)
+		.finalize()
+}
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Compound<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Compound::build(member("posts", 1)?)
+		.union_all(member("comments", 2)?)
+		.order_by([("id", false)])
+		.limit(10)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_intersect<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Compound<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Compound::build(member("posts", 1)?)
+		.intersect(member("comments", 2)?)
+		.intersect(member("likes", 3)?)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_except<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Compound<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Compound::build(member("posts", 1)?)
+		.union(member("comments", 2)?)
+		.except(member("likes", 3)?)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_in_subquery<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::clause::Where<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::clause::Where::build()
+		.column_in(
+			"id",
+			sql_lang::statement::Compound::build(member("posts", 1)?)
+				.union(member("comments", 2)?)
+				.finalize()?,
+		)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_join<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::clause::SqlFrom<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::clause::SqlFrom::build("activity", "a")
+		.inner_join(
+			sql_lang::statement::Compound::build(member("posts", 1)?)
+				.intersect(member("comments", 2)?)
+				.finalize()?,
+			"both",
+			SqlOn::build().fk("a", "item_id", "both", "id").finalize()?,
+		)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_expression_subqueries<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::clause::Where<DB>>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::clause::Where::build()
+			.column_equal_to("status", 0i32)
+			.and_exists(
+				sql_lang::statement::Compound::build(member("posts", 1)?)
+					.union(member("comments", 2)?)
+					.finalize()?,
+			)
+			.and_expression(
+				ColumnReference::new("id").not_equal_to(ScalarSubquery::new(
+					sql_lang::statement::Compound::build(member("posts", 3)?)
+						.intersect(member("comments", 4)?)
+						.finalize()?,
+				)),
+			)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn test_quantified<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::clause::Where<DB>>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::clause::Where::build()
+			.column_equal_to("status", 0i32)
+			.and_expression(
+				ColumnReference::new("id").equal_to(any(sql_lang::statement::Compound::build(
+					member("posts", 1)?,
+				)
+				.union(member("comments", 2)?)
+				.finalize()?)?),
+			)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_mixed<DB: Database>() -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	expect_syntax_error(
+		sql_lang::statement::Compound::build(member::<DB>("posts", 1)?)
+			.union(member("comments", 2)?)
+			.intersect(member("likes", 3)?)
+			.finalize(),
+		SyntaxErrorKind::MixedCompoundOperators,
+	)?;
+
+	expect_syntax_error(
+		sql_lang::statement::Compound::build(member::<DB>("posts", 1)?)
+			.intersect(member("comments", 2)?)
+			.except(member("likes", 3)?)
+			.finalize(),
+		SyntaxErrorKind::MixedCompoundOperators,
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "id" from "posts" where "author"=$1 union all select "id" from "comments" where "author"=$2 order by "id" desc limit $3"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			10u32.into_sql_value(),
+		],
+	)?;
+
+	test_intersect::<DB>(
+		r#"select "id" from "posts" where "author"=$1 intersect select "id" from "comments" where "author"=$2 intersect select "id" from "likes" where "author"=$3"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)?;
+
+	test_except::<DB>(
+		r#"select "id" from "posts" where "author"=$1 union select "id" from "comments" where "author"=$2 except select "id" from "likes" where "author"=$3"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)?;
+
+	test_in_subquery::<DB>(
+		r#"where "id" in (select "id" from "posts" where "author"=$1 union select "id" from "comments" where "author"=$2)"#,
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_join::<DB>(
+		r#"from "activity" "a" inner join (select "id" from "posts" where "author"=$1 intersect select "id" from "comments" where "author"=$2) "both" on "a"."item_id" = "both"."id""#,
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_expression_subqueries::<DB>(
+		r#"where "status"=$1 and exists (select "id" from "posts" where "author"=$2 union select "id" from "comments" where "author"=$3) and "id" <> (select "id" from "posts" where "author"=$4 intersect select "id" from "comments" where "author"=$5)"#,
+		&[
+			0i32.into_sql_value(),
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+			4i32.into_sql_value(),
+		],
+	)?;
+
+	test_quantified::<DB>(
+		r#"where "status"=$1 and "id" = any (select "id" from "posts" where "author"=$2 union select "id" from "comments" where "author"=$3)"#,
+		&[
+			0i32.into_sql_value(),
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+		],
+	)?;
+
+	test_mixed::<DB>()
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select `id` from `posts` where `author`=? union all select `id` from `comments` where `author`=? order by `id` desc limit ?",
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			10u32.into_sql_value(),
+		],
+	)?;
+
+	test_intersect::<DB>(
+		"select `id` from `posts` where `author`=? intersect select `id` from `comments` where `author`=? intersect select `id` from `likes` where `author`=?",
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)?;
+
+	test_except::<DB>(
+		"select `id` from `posts` where `author`=? union select `id` from `comments` where `author`=? except select `id` from `likes` where `author`=?",
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)?;
+
+	test_in_subquery::<DB>(
+		"where `id` in (select `id` from `posts` where `author`=? union select `id` from `comments` where `author`=?)",
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_join::<DB>(
+		"from `activity` `a` inner join (select `id` from `posts` where `author`=? intersect select `id` from `comments` where `author`=?) `both` on `a`.`item_id` = `both`.`id`",
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_expression_subqueries::<DB>(
+		"where `status`=? and exists (select `id` from `posts` where `author`=? union select `id` from `comments` where `author`=?) and `id` <> (select `id` from `posts` where `author`=? intersect select `id` from `comments` where `author`=?)",
+		&[
+			0i32.into_sql_value(),
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+			4i32.into_sql_value(),
+		],
+	)?;
+
+	test_quantified::<DB>(
+		"where `status`=? and `id` = any (select `id` from `posts` where `author`=? union select `id` from `comments` where `author`=?)",
+		&[
+			0i32.into_sql_value(),
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+		],
+	)?;
+
+	test_mixed::<DB>()
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "id" from "posts" where "author"=? union all select "id" from "comments" where "author"=? order by "id" desc limit ?"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			10u32.into_sql_value(),
+		],
+	)?;
+
+	test_intersect::<DB>(
+		r#"select "id" from "posts" where "author"=? intersect select "id" from "comments" where "author"=? intersect select "id" from "likes" where "author"=?"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)?;
+
+	test_except::<DB>(
+		r#"select "id" from "posts" where "author"=? union select "id" from "comments" where "author"=? except select "id" from "likes" where "author"=?"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)?;
+
+	test_in_subquery::<DB>(
+		r#"where "id" in (select "id" from "posts" where "author"=? union select "id" from "comments" where "author"=?)"#,
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_join::<DB>(
+		r#"from "activity" "a" inner join (select "id" from "posts" where "author"=? intersect select "id" from "comments" where "author"=?) "both" on "a"."item_id" = "both"."id""#,
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_expression_subqueries::<DB>(
+		r#"where "status"=? and exists (select "id" from "posts" where "author"=? union select "id" from "comments" where "author"=?) and "id" <> (select "id" from "posts" where "author"=? intersect select "id" from "comments" where "author"=?)"#,
+		&[
+			0i32.into_sql_value(),
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+			4i32.into_sql_value(),
+		],
+	)?;
+
+	test_mixed::<DB>()
+}
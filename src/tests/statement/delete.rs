@@ -44,3 +44,14 @@ fn mysql() -> Result<(), SyntaxError> {
 		&[1i32.into_sql_value(), 2i32.into_sql_value()],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"delete from "some_table" where "col1"=? or "col2"=?"#,
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)
+}
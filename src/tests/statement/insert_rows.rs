@@ -0,0 +1,93 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let mut statements: Vec<Sql<DB>> =
+		sql_lang::statement::InsertRows::build("some_table", ["col1", "col2"])
+			.row([1i32, 2i32])
+			.rows([[3i32, 4i32]])
+			.finalize()?
+			.into_statements();
+
+	assert_eq!(statements.len(), 1);
+	compare_sql(&statements.remove(0), target_text, target_params)?;
+
+	// Each row needs 3 bind parameters, so the rows must be split over 2 or more statements
+	let row_count = DB::sql_bind_parameter_limit() / 3 + 1;
+	let statements = sql_lang::statement::InsertRows::build("some_table", ["a", "b", "c"])
+		.rows((0..row_count).map(|i| [i as i32; 3]))
+		.finalize()?
+		.into_statements();
+
+	assert_eq!(statements.len(), 2);
+	assert_eq!(statements[0].params().len(), (row_count - 1) * 3);
+	assert_eq!(statements[1].params().len(), 3);
+
+	let error = sql_lang::statement::InsertRows::<DB>::build("some_table", ["col1", "col2"])
+		.row([1i32, 2i32])
+		.row([3i32])
+		.finalize()
+		.unwrap_err();
+
+	assert!(matches!(
+		error.kind(),
+		crate::error::SyntaxErrorKind::MismatchedInsertRowLength
+	));
+
+	Ok(())
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"insert into "some_table" ("col1","col2") values ($1,$2),($3,$4)"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+			4i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"insert into `some_table` (`col1`,`col2`) values (?,?),(?,?)",
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+			4i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"insert into "some_table" ("col1","col2") values (?,?),(?,?)"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+			4i32.into_sql_value(),
+		],
+	)
+}
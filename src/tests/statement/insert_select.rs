@@ -0,0 +1,139 @@
+use crate::error::SyntaxErrorKind;
+use crate::tests::{compare_sql, expect_syntax_error};
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Insert<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Insert::build("live_table")
+		.select(
+			["col1", "col2"],
+			sql_lang::statement::Select::build("archive_table")
+				.select_column("col1")
+				.select_column("col2")
+				.where_column_equal_to("one", 1i32)
+				.where_column_equal_to("two", 2i32)
+				.finalize()?,
+		)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_bound_projection<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Insert<DB>>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Insert::build("live_table")
+			.select(
+				["col1", "col2"],
+				sql_lang::statement::Select::build("archive_table")
+					.select_column("col1")
+					.select_expression(ColumnReference::new("col2") + 10i32, "col2")
+					.where_column_equal_to("one", 1i32)
+					.finalize()?,
+			)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_invalid<DB: Database>() -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	expect_syntax_error(
+		sql_lang::statement::Insert::<DB>::build("live_table")
+			.column("col1", 1i32)
+			.select(
+				["col1"],
+				sql_lang::statement::Select::build("archive_table")
+					.select_column("col1")
+					.finalize()?,
+			)
+			.finalize(),
+		SyntaxErrorKind::ConflictingInsertSources,
+	)?;
+
+	expect_syntax_error(
+		sql_lang::statement::Insert::<DB>::build("live_table")
+			.select(
+				["col1", "col2"],
+				sql_lang::statement::Select::build("archive_table")
+					.select_column("col1")
+					.finalize()?,
+			)
+			.finalize(),
+		SyntaxErrorKind::MismatchedInsertRowLength,
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"insert into "live_table" ("col1","col2") select "col1", "col2" from "archive_table" where "one"=$1 and "two"=$2"#,
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_bound_projection::<DB>(
+		r#"insert into "live_table" ("col1","col2") select "col1", ("col2" + $1) as "col2" from "archive_table" where "one"=$2"#,
+		&[10i32.into_sql_value(), 1i32.into_sql_value()],
+	)?;
+
+	test_invalid::<DB>()
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"insert into `live_table` (`col1`,`col2`) select `col1`, `col2` from `archive_table` where `one`=? and `two`=?",
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_bound_projection::<DB>(
+		"insert into `live_table` (`col1`,`col2`) select `col1`, (`col2` + ?) as `col2` from `archive_table` where `one`=?",
+		&[10i32.into_sql_value(), 1i32.into_sql_value()],
+	)?;
+
+	test_invalid::<DB>()
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"insert into "live_table" ("col1","col2") select "col1", "col2" from "archive_table" where "one"=? and "two"=?"#,
+		&[1i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_bound_projection::<DB>(
+		r#"insert into "live_table" ("col1","col2") select "col1", ("col2" + ?) as "col2" from "archive_table" where "one"=?"#,
+		&[10i32.into_sql_value(), 1i32.into_sql_value()],
+	)?;
+
+	test_invalid::<DB>()
+}
@@ -1,3 +1,9 @@
+mod compound;
 mod delete;
+mod delete_all_rows;
+mod insert_rows;
+mod insert_select;
+mod schema_qualified;
 mod select;
 mod update;
+mod update_expression;
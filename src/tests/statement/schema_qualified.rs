@@ -0,0 +1,340 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{crud, sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test_select<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build(("reporting", "sales"))
+			.select_column("id")
+			.select_expression(
+				TableAndColumnReference::with_schema("reporting", "sales", "total"),
+				"total",
+			)
+			.where_column_equal_to("region_id", 1i32)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_join<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError> {
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build_with_join(
+			sql_lang::clause::SqlFrom::build(("reporting", "sales"), "s")
+				.inner_join(
+					TableName::with_schema("crm", "customers"),
+					"c",
+					sql_lang::clause::SqlOn::build()
+						.fk("s", "customer_id", "c", "id")
+						.finalize()?,
+				)
+				.finalize()?,
+		)
+		.select_column("c", "name")
+		.finalize()?
+		.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_columns<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sales = ("reporting", "sales");
+
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Select::build_with_join(sql_lang::clause::SqlFrom::from_table_name(
+			sales,
+		))
+		.select_column(sales, "id")
+		.select_expression(
+			row_number().over(window().partition_by_table_column(sales, "region_id")),
+			"position",
+		)
+		.with_where_clause(
+			sql_lang::clause::Where::build_with_join()
+				.column_equal_to(sales, "region_id", 1i32)
+				.finalize()?,
+		)
+		.order_by([(sales, "total", false)])
+		.finalize()?
+		.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[cfg(feature = "postgres")]
+fn test_distinct_on<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError> {
+	let sales = ("reporting", "sales");
+
+	let sql: Sql<DB> = sql_lang::statement::Select::build_with_join(
+		sql_lang::clause::SqlFrom::from_table_name(sales),
+	)
+	.select_column(sales, "id")
+	.distinct_on([(sales, "region_id")])
+	.finalize()?
+	.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_update<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Update::build(("reporting", "sales"))
+		.update_column("total", 2i32)
+		.where_column_equal_to("id", 3i32)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_insert<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Insert::build(("reporting", "sales"))
+		.column("id", 3i32)
+		.column("total", 2i32)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_insert_rows<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let mut statements: Vec<Sql<DB>> =
+		sql_lang::statement::InsertRows::build(("reporting", "sales"), ["id", "total"])
+			.row([3i32, 2i32])
+			.finalize()?
+			.into_statements();
+
+	assert_eq!(statements.len(), 1);
+	compare_sql(&statements.remove(0), target_text, target_params)
+}
+
+fn test_delete<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Delete::build(("reporting", "sales"))
+		.where_column_equal_to("id", 3i32)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_upsert<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = crud::replace_row(("reporting", "sales"))
+		.key_columns(["id"])
+		.insert_column("id", 3i32)
+		.replace_column("total", 2i32)
+		.into_upsert()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test_select::<DB>(
+		r#"select "id", "reporting"."sales"."total" as "total" from "reporting"."sales" where "region_id"=$1"#,
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_join::<DB>(
+		r#"select "c"."name" from "reporting"."sales" "s" inner join "crm"."customers" "c" on "s"."customer_id" = "c"."id""#,
+		&[],
+	)?;
+
+	test_columns::<DB>(
+		r#"select "reporting"."sales"."id", row_number() over (partition by "reporting"."sales"."region_id") as "position" from "reporting"."sales" where "reporting"."sales"."region_id"=$1 order by "reporting"."sales"."total" desc"#,
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_distinct_on::<DB>(
+		r#"select distinct on ("reporting"."sales"."region_id") "reporting"."sales"."id" from "reporting"."sales""#,
+		&[],
+	)?;
+
+	test_update::<DB>(
+		r#"update "reporting"."sales" set "total"=$1 where "id"=$2"#,
+		&[2i32.into_sql_value(), 3i32.into_sql_value()],
+	)?;
+
+	test_insert::<DB>(
+		r#"insert into "reporting"."sales" ("id","total") values ($1,$2)"#,
+		&[3i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_insert_rows::<DB>(
+		r#"insert into "reporting"."sales" ("id","total") values ($1,$2)"#,
+		&[3i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_delete::<DB>(
+		r#"delete from "reporting"."sales" where "id"=$1"#,
+		&[3i32.into_sql_value()],
+	)?;
+
+	test_upsert::<DB>(
+		r#"insert into "reporting"."sales" ("id","total") values ($1,$2) on conflict ("id") do update set "total"=$3"#,
+		&[
+			3i32.into_sql_value(),
+			2i32.into_sql_value(),
+			2i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test_select::<DB>(
+		"select `id`, `reporting`.`sales`.`total` as `total` from `reporting`.`sales` where `region_id`=?",
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_join::<DB>(
+		"select `c`.`name` from `reporting`.`sales` `s` inner join `crm`.`customers` `c` on `s`.`customer_id` = `c`.`id`",
+		&[],
+	)?;
+
+	test_columns::<DB>(
+		"select `reporting`.`sales`.`id`, row_number() over (partition by `reporting`.`sales`.`region_id`) as `position` from `reporting`.`sales` where `reporting`.`sales`.`region_id`=? order by `reporting`.`sales`.`total` desc",
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_update::<DB>(
+		"update `reporting`.`sales` set `total`=? where `id`=?",
+		&[2i32.into_sql_value(), 3i32.into_sql_value()],
+	)?;
+
+	test_insert::<DB>(
+		"insert into `reporting`.`sales` (`id`,`total`) values (?,?)",
+		&[3i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_insert_rows::<DB>(
+		"insert into `reporting`.`sales` (`id`,`total`) values (?,?)",
+		&[3i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_delete::<DB>(
+		"delete from `reporting`.`sales` where `id`=?",
+		&[3i32.into_sql_value()],
+	)?;
+
+	test_upsert::<DB>(
+		"insert into `reporting`.`sales` (`id`,`total`) values (?,?) on duplicate key update `total`=?",
+		&[
+			3i32.into_sql_value(),
+			2i32.into_sql_value(),
+			2i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test_select::<DB>(
+		r#"select "id", "reporting"."sales"."total" as "total" from "reporting"."sales" where "region_id"=?"#,
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_join::<DB>(
+		r#"select "c"."name" from "reporting"."sales" "s" inner join "crm"."customers" "c" on "s"."customer_id" = "c"."id""#,
+		&[],
+	)?;
+
+	test_columns::<DB>(
+		r#"select "reporting"."sales"."id", row_number() over (partition by "reporting"."sales"."region_id") as "position" from "reporting"."sales" where "reporting"."sales"."region_id"=? order by "reporting"."sales"."total" desc"#,
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_update::<DB>(
+		r#"update "reporting"."sales" set "total"=? where "id"=?"#,
+		&[2i32.into_sql_value(), 3i32.into_sql_value()],
+	)?;
+
+	test_insert::<DB>(
+		r#"insert into "reporting"."sales" ("id","total") values (?,?)"#,
+		&[3i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_insert_rows::<DB>(
+		r#"insert into "reporting"."sales" ("id","total") values (?,?)"#,
+		&[3i32.into_sql_value(), 2i32.into_sql_value()],
+	)?;
+
+	test_delete::<DB>(
+		r#"delete from "reporting"."sales" where "id"=?"#,
+		&[3i32.into_sql_value()],
+	)?;
+
+	test_upsert::<DB>(
+		r#"insert into "reporting"."sales" ("id","total") values (?,?) on conflict ("id") do update set "total"=?"#,
+		&[
+			3i32.into_sql_value(),
+			2i32.into_sql_value(),
+			2i32.into_sql_value(),
+		],
+	)
+}
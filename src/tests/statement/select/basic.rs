@@ -41,3 +41,14 @@ fn mysql() -> Result<(), SyntaxError> {
 		&[1i32.into_sql_value()],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "col1", "col2" as "foo" from "some_table" where "one"=?"#,
+		&[1i32.into_sql_value()],
+	)
+}
@@ -0,0 +1,58 @@
+use crate::error::SyntaxErrorKind;
+use crate::tests::{compare_sql, expect_syntax_error};
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::statement::Select<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Select::build("some_table")
+		.select_columns(["col1", "col2", "col3"])
+		.distinct_on(["col1", "col2"])
+		.order_by([("col1", true), ("col2", true), ("col3", false)])
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select distinct on ("col1", "col2") "col1", "col2", "col3" from "some_table" order by "col1" asc, "col2" asc, "col3" desc"#,
+		&[],
+	)?;
+
+	expect_syntax_error(
+		sql_lang::statement::Select::<DB>::build("some_table")
+			.select_columns(["col1", "col2"])
+			.distinct_on(Vec::<String>::new())
+			.finalize(),
+		SyntaxErrorKind::MissingDistinctOnColumns,
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		test::<sqlx::MySql>("", &[]),
+		SyntaxErrorKind::UnsupportedFeature,
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		test::<sqlx::Sqlite>("", &[]),
+		SyntaxErrorKind::UnsupportedFeature,
+	)
+}
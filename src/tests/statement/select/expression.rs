@@ -53,3 +53,14 @@ fn mysql() -> Result<(), SyntaxError> {
 		],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "col1", (select "foo" from "another_table" where "id"=?) as "some_alias" from "some_table" where "one"=?"#,
+		&[123i32.into_sql_value(), 1i32.into_sql_value()],
+	)
+}
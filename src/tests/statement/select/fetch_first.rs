@@ -0,0 +1,56 @@
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+use crate::error::SyntaxErrorKind;
+use crate::tests::compare_sql;
+#[cfg(any(feature = "mysql", feature = "sqlite"))]
+use crate::tests::expect_syntax_error;
+use crate::value::Value;
+#[cfg(feature = "postgres")]
+use crate::IntoSqlValue;
+use crate::{sql_lang, Database, IntoSql, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::statement::Select<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Select::build("some_table")
+		.select_column("col1")
+		.order_by([("col1", true)])
+		.offset(200)
+		.fetch_first(10)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "col1" from "some_table" order by "col1" asc offset $1 rows fetch first $2 rows only"#,
+		&[200u32.into_sql_value(), 10u32.into_sql_value()],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		test::<sqlx::MySql>("", &[]),
+		SyntaxErrorKind::UnsupportedFeature,
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		test::<sqlx::Sqlite>("", &[]),
+		SyntaxErrorKind::UnsupportedFeature,
+	)
+}
@@ -90,3 +90,18 @@ fn mysql() -> Result<(), SyntaxError> {
 		],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "col1", count("col2") as "some_total" from "some_table" where "foo_id"=? and "foo555"=? group by "col1" having count("col2") >= ? order by "some_total" desc"#,
+		&[
+			1i32.into_sql_value(),
+			"bar555".into_sql_value(),
+			5i32.into_sql_value(),
+		],
+	)
+}
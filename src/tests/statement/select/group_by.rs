@@ -48,3 +48,14 @@ fn mysql() -> Result<(), SyntaxError> {
 		&[1u32.into_sql_value()],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "col1" from "some_table" group by "col2" having "foo"=?"#,
+		&[1u32.into_sql_value()],
+	)
+}
@@ -67,3 +67,18 @@ fn mysql() -> Result<(), SyntaxError> {
 		],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "t1"."col1", "t2"."col2" from "table1" "t1" inner join "table2" "t2" on "t2"."fk_t1_id" = "t1"."id" where "t1"."col1"=? and "t1"."col2"=? and "t2"."col1"=?"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			3i32.into_sql_value(),
+		],
+	)
+}
@@ -0,0 +1,90 @@
+use crate::error::SyntaxErrorKind;
+use crate::tests::{compare_sql, expect_syntax_error};
+use crate::value::Value;
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+use crate::IntoSqlValue;
+use crate::{sql_lang, Database, IntoSql, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::statement::Select<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Select::build("jobs")
+		.select_column("id")
+		.where_column_equal_to("status", "pending")
+		.order_by([("id", true)])
+		.limit(1)
+		.for_update()
+		.skip_locked()
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn test_share<DB: Database>(target_text: &str) -> Result<(), SyntaxError>
+where
+	Sql<DB>: From<sql_lang::statement::Select<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Select::build("accounts")
+		.select_column("balance")
+		.for_share()
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, &[])
+}
+
+fn test_missing_lock_mode<DB: Database>() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		sql_lang::statement::Select::<DB>::build("jobs")
+			.select_column("id")
+			.nowait()
+			.finalize(),
+		SyntaxErrorKind::MissingLockMode,
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "id" from "jobs" where "status"=$1 order by "id" asc limit $2 for update skip locked"#,
+		&["pending".into_sql_value(), 1u32.into_sql_value()],
+	)?;
+
+	test_share::<DB>(r#"select "balance" from "accounts" for share"#)?;
+
+	test_missing_lock_mode::<DB>()
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select `id` from `jobs` where `status`=? order by `id` asc limit ? for update skip locked",
+		&["pending".into_sql_value(), 1u32.into_sql_value()],
+	)?;
+
+	test_share::<DB>("select `balance` from `accounts` lock in share mode")?;
+
+	test_missing_lock_mode::<DB>()
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	expect_syntax_error(test::<DB>("", &[]), SyntaxErrorKind::UnsupportedFeature)?;
+
+	test_missing_lock_mode::<DB>()
+}
@@ -1,9 +1,15 @@
 mod basic;
+mod distinct;
+mod distinct_on;
 mod expression;
+mod fetch_first;
 mod full;
 mod group_by;
 mod group_by_iter;
 mod join;
+mod locking;
 mod order_by;
 mod order_by_iter;
 mod order_by_iter_with_join;
+mod pagination;
+mod select_all;
@@ -40,3 +40,14 @@ fn mysql() -> Result<(), SyntaxError> {
 
 	test::<DB>("select `col1` from `some_table` order by `col2` asc", &[])
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "col1" from "some_table" order by "col2" asc"#,
+		&[],
+	)
+}
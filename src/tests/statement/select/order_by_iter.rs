@@ -33,3 +33,11 @@ fn mysql() -> Result<(), SyntaxError> {
 
 	test::<DB>("select `col1` from `foo` order by `col1` desc", &[])
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(r#"select "col1" from "foo" order by "col1" desc"#, &[])
+}
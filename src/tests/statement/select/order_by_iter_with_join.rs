@@ -41,3 +41,14 @@ fn mysql() -> Result<(), SyntaxError> {
 		&[],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "f"."col1" from "foo" "f" order by "f"."col1" asc"#,
+		&[],
+	)
+}
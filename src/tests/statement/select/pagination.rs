@@ -0,0 +1,67 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Select<DB>>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Select::build("some_table")
+		.select_column("col1")
+		.where_column_equal_to("one", 1i32)
+		.limit(10)
+		.offset(200)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select "col1" from "some_table" where "one"=$1 limit $2 offset $3"#,
+		&[
+			1i32.into_sql_value(),
+			10u32.into_sql_value(),
+			200u32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select `col1` from `some_table` where `one`=? limit ? offset ?",
+		&[
+			1i32.into_sql_value(),
+			10u32.into_sql_value(),
+			200u32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select "col1" from "some_table" where "one"=? limit ? offset ?"#,
+		&[
+			1i32.into_sql_value(),
+			10u32.into_sql_value(),
+			200u32.into_sql_value(),
+		],
+	)
+}
@@ -0,0 +1,166 @@
+use crate::error::SyntaxErrorKind;
+use crate::sql_lang::clause::SqlOn;
+use crate::sql_lang::expression::TableName;
+use crate::tests::{compare_sql, expect_syntax_error};
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+{
+	let sql: Sql<DB> = sql_lang::statement::Select::build("users")
+		.select_all()
+		.select_column("name")
+		.where_column_equal_to("id", 1i32)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_from<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError> {
+	let sql: Sql<DB> = sql_lang::statement::Select::build_with_join(
+		sql_lang::clause::SqlFrom::build("users", "u")
+			.inner_join(
+				"orders",
+				"o",
+				SqlOn::build().fk("o", "user_id", "u", "id").finalize()?,
+			)
+			.finalize()?,
+	)
+	.select_column("o", "total")
+	.select_all_from("u")
+	.finalize()?
+	.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_from_schema<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError> {
+	let sql: Sql<DB> = sql_lang::statement::Select::build_with_join(
+		sql_lang::clause::SqlFrom::from_table_name(("reporting", "sales")),
+	)
+	.select_all_from(TableName::with_schema("reporting", "sales"))
+	.finalize()?
+	.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_insert<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError> {
+	let sql: Sql<DB> = sql_lang::statement::Insert::build("users_archive")
+		.select(
+			["id", "name"],
+			sql_lang::statement::Select::build("users")
+				.select_all()
+				.finalize()?,
+		)
+		.finalize()?
+		.into_sql();
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+fn test_misplaced<DB: Database>() -> Result<(), SyntaxError> {
+	expect_syntax_error(
+		sql_lang::statement::Select::<DB>::build("users")
+			.select_column("name")
+			.select_all()
+			.finalize(),
+		SyntaxErrorKind::MisplacedSelectAll,
+	)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"select *, "name" from "users" where "id"=$1"#,
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_from::<DB>(
+		r#"select "o"."total", "u".* from "users" "u" inner join "orders" "o" on "o"."user_id" = "u"."id""#,
+		&[],
+	)?;
+
+	test_from_schema::<DB>(
+		r#"select "reporting"."sales".* from "reporting"."sales""#,
+		&[],
+	)?;
+
+	test_insert::<DB>(
+		r#"insert into "users_archive" ("id","name") select * from "users""#,
+		&[],
+	)?;
+
+	test_misplaced::<DB>()
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"select *, `name` from `users` where `id`=?",
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_from::<DB>(
+		"select `o`.`total`, `u`.* from `users` `u` inner join `orders` `o` on `o`.`user_id` = `u`.`id`",
+		&[],
+	)?;
+
+	test_from_schema::<DB>("select `reporting`.`sales`.* from `reporting`.`sales`", &[])?;
+
+	test_insert::<DB>(
+		"insert into `users_archive` (`id`,`name`) select * from `users`",
+		&[],
+	)?;
+
+	test_misplaced::<DB>()
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"select *, "name" from "users" where "id"=?"#,
+		&[1i32.into_sql_value()],
+	)?;
+
+	test_from::<DB>(
+		r#"select "o"."total", "u".* from "users" "u" inner join "orders" "o" on "o"."user_id" = "u"."id""#,
+		&[],
+	)?;
+
+	test_from_schema::<DB>(
+		r#"select "reporting"."sales".* from "reporting"."sales""#,
+		&[],
+	)?;
+
+	test_insert::<DB>(
+		r#"insert into "users_archive" ("id","name") select * from "users""#,
+		&[],
+	)?;
+
+	test_misplaced::<DB>()
+}
@@ -52,3 +52,19 @@ fn mysql() -> Result<(), SyntaxError> {
 		],
 	)
 }
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"update "some_table" set "one"=?,"two"=? where "10"=? and "11"=?"#,
+		&[
+			1i32.into_sql_value(),
+			2i32.into_sql_value(),
+			10i32.into_sql_value(),
+			11i32.into_sql_value(),
+		],
+	)
+}
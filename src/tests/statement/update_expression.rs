@@ -0,0 +1,71 @@
+use crate::tests::compare_sql;
+use crate::value::Value;
+use crate::{sql_lang, Database, IntoSql, IntoSqlValue, Sql, SyntaxError};
+
+fn test<DB: Database>(
+	target_text: &str,
+	target_params: &[Option<Value<DB>>],
+) -> Result<(), SyntaxError>
+where
+	i32: IntoSqlValue<DB>,
+	Sql<DB>: From<sql_lang::statement::Update<DB>>,
+{
+	let sql: Sql<DB> = {
+		use sql_lang::expression::*;
+
+		sql_lang::statement::Update::build("counters")
+			.update_column_expression("hits", ColumnReference::new("hits") + 1i32)
+			.update_column_expression("updated_at", current_datetime())
+			.update_column("label", "busy")
+			.where_column_equal_to("id", 7i32)
+			.finalize()?
+			.into_sql()
+	};
+
+	compare_sql(&sql, target_text, target_params)
+}
+
+#[test]
+#[cfg(feature = "postgres")]
+fn postgres() -> Result<(), SyntaxError> {
+	type DB = sqlx::Postgres;
+
+	test::<DB>(
+		r#"update "counters" set "hits"=("hits" + $1),"updated_at"=current_timestamp(),"label"=$2 where "id"=$3"#,
+		&[
+			1i32.into_sql_value(),
+			"busy".into_sql_value(),
+			7i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "mysql")]
+fn mysql() -> Result<(), SyntaxError> {
+	type DB = sqlx::MySql;
+
+	test::<DB>(
+		"update `counters` set `hits`=(`hits` + ?),`updated_at`=current_timestamp(),`label`=? where `id`=?",
+		&[
+			1i32.into_sql_value(),
+			"busy".into_sql_value(),
+			7i32.into_sql_value(),
+		],
+	)
+}
+
+#[test]
+#[cfg(feature = "sqlite")]
+fn sqlite() -> Result<(), SyntaxError> {
+	type DB = sqlx::Sqlite;
+
+	test::<DB>(
+		r#"update "counters" set "hits"=("hits" + ?),"updated_at"=current_timestamp(),"label"=? where "id"=?"#,
+		&[
+			1i32.into_sql_value(),
+			"busy".into_sql_value(),
+			7i32.into_sql_value(),
+		],
+	)
+}
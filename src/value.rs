@@ -148,6 +148,24 @@ impl<DB: Database, T: IntoSqlValue<DB> + Clone> IntoSqlValue<DB> for &T {
 	}
 }
 
+/// Converts a collection of values into a single array value, for vendors with array types.
+///
+/// Only implemented for Postgres, where it allows comparing against any number of values with a
+/// single bind parameter (i.e. `= any($1)`).
+pub trait IntoSqlArray<DB: Database> {
+	fn into_sql_array(self) -> Option<Value<DB>>;
+}
+
+/// Blanket conversion from &[T], for the element types supported as a Vec<T>
+impl<DB: Database, T: Clone> IntoSqlArray<DB> for &[T]
+where
+	Vec<T>: IntoSqlArray<DB>,
+{
+	fn into_sql_array(self) -> Option<Value<DB>> {
+		self.to_vec().into_sql_array()
+	}
+}
+
 // TODO: can't impl this (or generic version) due to orphan rules
 /*impl<DB: Database> From<u32> for Option<Value<DB>> {
 	fn from(value: u32) -> Self {
@@ -215,4 +233,6 @@ pub(crate) enum ValueLogicalKind {
 	Time,
 	Text,
 	Bytes,
+	#[cfg(feature = "postgres")]
+	Array,
 }